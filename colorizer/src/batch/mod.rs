@@ -0,0 +1,132 @@
+#[cfg(test)]
+#[path = "./test/batch.test.rs"]
+mod test;
+
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Write},
+};
+
+use colorizer::color::{Color, ParseError};
+
+/// What becomes of a line holding no color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Leave the line out of the output
+    Skip,
+    /// Stop at the line
+    Fail,
+    /// Write the line unchanged
+    Echo,
+}
+
+impl ErrorPolicy {
+    pub const ALL: [ErrorPolicy; 3] = [ErrorPolicy::Skip, ErrorPolicy::Fail, ErrorPolicy::Echo];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorPolicy::Skip => "skip",
+            ErrorPolicy::Fail => "fail",
+            ErrorPolicy::Echo => "echo",
+        }
+    }
+
+    /// Finds a policy by its name, ignoring case
+    pub fn from_name(name: &str) -> Option<ErrorPolicy> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl Display for ErrorPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Reason a batch conversion stopped
+#[derive(Debug)]
+pub enum BatchError {
+    Io(io::Error),
+    /// Line, counted from 1, holding no color under [`ErrorPolicy::Fail`]
+    Parse {
+        line: usize,
+        text: String,
+        error: ParseError,
+    },
+}
+
+impl From<io::Error> for BatchError {
+    fn from(error: io::Error) -> Self {
+        BatchError::Io(error)
+    }
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchError::Io(error) => write!(f, "{}", error),
+            BatchError::Parse { line, error, .. } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+/// How many lines of a batch were converted, skipped and echoed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    pub converted: usize,
+    pub skipped: usize,
+    pub echoed: usize,
+}
+
+/// The first color of `line`, which may be a bare color or free text around one
+///
+/// When there is none, the error is the one of parsing the whole trimmed line.
+pub fn parse_line(line: &str) -> Result<Color, ParseError> {
+    match Color::find_all(line).into_iter().next() {
+        Some(found) => Ok(found.color),
+        None => Color::try_parse(line.trim().to_string()),
+    }
+}
+
+/// Writes one line to `writer` for every line of `reader`, its first color written by `convert`
+///
+/// Lines without a color are handled according to `policy`. Blank lines are left out, except
+/// under [`ErrorPolicy::Echo`] which keeps every output line in front of its input line.
+pub fn convert_batch(
+    reader: impl BufRead,
+    writer: &mut impl Write,
+    policy: ErrorPolicy,
+    convert: impl Fn(&Color) -> String,
+) -> Result<BatchSummary, BatchError> {
+    let mut summary = BatchSummary::default();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            if policy == ErrorPolicy::Echo {
+                writeln!(writer, "{}", line)?;
+            }
+            continue;
+        }
+        match (parse_line(&line), policy) {
+            (Ok(color), _) => {
+                writeln!(writer, "{}", convert(&color))?;
+                summary.converted += 1;
+            }
+            (Err(_), ErrorPolicy::Skip) => summary.skipped += 1,
+            (Err(_), ErrorPolicy::Echo) => {
+                writeln!(writer, "{}", line)?;
+                summary.echoed += 1;
+            }
+            (Err(error), ErrorPolicy::Fail) => {
+                return Err(BatchError::Parse {
+                    line: index + 1,
+                    text: line.trim().to_string(),
+                    error,
+                });
+            }
+        }
+    }
+    Ok(summary)
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+pub mod tests {
+    use colorizer::color::{Color, RGB};
+
+    use crate::batch::{BatchError, BatchSummary, ErrorPolicy, convert_batch, parse_line};
+
+    fn to_hex(color: &Color) -> String {
+        format!("{:X}", RGB::from(color.clone()))
+    }
+
+    fn run(input: &str, policy: ErrorPolicy) -> (String, Result<BatchSummary, BatchError>) {
+        let mut output = vec![];
+        let result = convert_batch(input.as_bytes(), &mut output, policy, to_hex);
+        (String::from_utf8(output).unwrap(), result)
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("  --brand-blue: #3b82f6; /* blue */").unwrap(),
+            Color::from(RGB::new(0x3b, 0x82, 0xf6))
+        );
+        assert_eq!(
+            parse_line("ff0000").unwrap(),
+            Color::from(RGB::new(255, 0, 0))
+        );
+        assert!(parse_line("# brand colors").is_err());
+    }
+
+    #[test]
+    fn test_convert_batch_policies() {
+        let input = "red\n\n# accents\nhsl(120 100% 25%)\r\nrgb(0 0 255\n";
+
+        let (output, summary) = run(input, ErrorPolicy::Skip);
+        assert_eq!(output, "#FF0000\n#008000\n");
+        assert_eq!(
+            summary.unwrap(),
+            BatchSummary {
+                converted: 2,
+                skipped: 2,
+                echoed: 0
+            }
+        );
+
+        let (output, summary) = run(input, ErrorPolicy::Echo);
+        assert_eq!(output, "#FF0000\n\n# accents\n#008000\nrgb(0 0 255\n");
+        assert_eq!(summary.unwrap().echoed, 2);
+
+        let (output, summary) = run(input, ErrorPolicy::Fail);
+        assert_eq!(output, "#FF0000\n");
+        match summary {
+            Err(BatchError::Parse { line, text, .. }) => {
+                assert_eq!(line, 3);
+                assert_eq!(text, "# accents");
+            }
+            _ => panic!("expected the third line to fail"),
+        }
+    }
+
+    #[test]
+    fn test_echo_keeps_lines_aligned() {
+        let input = "red\n\n  \nnot a color\nblue\n";
+        let (output, summary) = run(input, ErrorPolicy::Echo);
+        assert_eq!(output, "#FF0000\n\n  \nnot a color\n#0000FF\n");
+        assert_eq!(output.lines().count(), input.lines().count());
+        assert_eq!(summary.unwrap().echoed, 1);
+
+        let (output, _) = run(input, ErrorPolicy::Skip);
+        assert_eq!(output, "#FF0000\n#0000FF\n");
+    }
+
+    #[test]
+    fn test_error_policy_names() {
+        for policy in ErrorPolicy::ALL {
+            assert_eq!(ErrorPolicy::from_name(policy.name()), Some(policy));
+        }
+        assert_eq!(ErrorPolicy::from_name("ECHO"), Some(ErrorPolicy::Echo));
+        assert_eq!(ErrorPolicy::from_name("ignore"), None);
+    }
+}
@@ -0,0 +1,326 @@
+#[cfg(test)]
+#[path = "./test/cli.test.rs"]
+mod test;
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+use clap_complete::Shell;
+
+use colorizer::color::{BlackGeneration, Color, GamutMapping, Harmony, MixSpace, TargetFormat};
+
+use crate::{batch::ErrorPolicy, clipboard::BackendChoice, snap::DEFAULT_TOLERANCE};
+
+/// Converts colors between formats, and audits the colors of source code
+///
+/// Without a subcommand, converts a color as `colorizer convert` does.
+#[derive(Debug, Parser)]
+#[command(
+    name = "colorizer",
+    version,
+    about,
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    #[command(flatten)]
+    pub convert: ConvertArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Convert a color, asking for the format unless `--to` is given, and copy it
+    Convert {
+        /// Color to convert, asked for when missing
+        #[arg(conflicts_with_all = ["input", "clipboard"])]
+        color: Option<String>,
+        #[command(flatten)]
+        args: ConvertArgs,
+    },
+    /// Print a color in every format
+    Inspect {
+        color: String,
+        /// Print every format, the detected one, the name and the contrast as JSON
+        #[arg(long)]
+        json: bool,
+        #[command(flatten)]
+        format: FormatArgs,
+    },
+    /// WCAG 2 contrast ratio of a text color over a background color
+    Contrast {
+        #[arg(value_parser = parse_color)]
+        foreground: Color,
+        #[arg(value_parser = parse_color)]
+        background: Color,
+    },
+    /// Mix two colors, as CSS `color-mix()` does
+    Mix(MixArgs),
+    /// Build a color scheme or a shade ramp from a color
+    Palette(PaletteArgs),
+    /// Convert every line of a file or of stdin, such as an exported palette
+    Batch(BatchArgs),
+    /// List the color literals of source files and directories
+    Scan {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+        /// Report CSS color names too, such as `red`
+        #[arg(long)]
+        named: bool,
+        /// Files and directories to scan
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+    },
+    /// Convert the color literals of source files and directories in place
+    Rewrite(RewriteArgs),
+    /// List or replace the colors close to a palette without being in it
+    Snap(SnapArgs),
+    /// Print a shell completion script
+    Completions { shell: Shell },
+    /// Print the man page
+    Man,
+}
+
+/// How converted values are written
+#[derive(Debug, Args)]
+pub struct FormatArgs {
+    /// Decimals shown on the converted values
+    #[arg(short, long, default_value_t = 0)]
+    pub precision: usize,
+    /// How colors are brought inside of narrower gamuts: clip or perceptual
+    #[arg(long, value_parser = parse_gamut_mapping, default_value = "perceptual")]
+    pub gamut_mapping: GamutMapping,
+    /// How CMYK black is computed: naive, ucr:<start> or gcr:<amount>
+    #[arg(long, value_parser = parse_black_generation, default_value = "naive")]
+    pub black_generation: BlackGeneration,
+}
+
+impl FormatArgs {
+    /// `color` written in the `to` format with these options
+    pub fn format(&self, color: &Color, to: TargetFormat) -> String {
+        to.format(
+            color,
+            self.precision,
+            self.gamut_mapping,
+            self.black_generation,
+        )
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ConvertArgs {
+    /// Color to convert
+    #[arg(short, long, conflicts_with = "clipboard")]
+    pub input: Option<String>,
+    /// Read the color from the clipboard
+    #[arg(short, long)]
+    pub clipboard: bool,
+    /// How the clipboard is reached: auto, native, osc52, wl-copy, xclip or xsel
+    #[arg(
+        long,
+        env = "COLORIZER_CLIPBOARD",
+        value_parser = parse_backend_choice,
+        default_value = "auto"
+    )]
+    pub clipboard_backend: BackendChoice,
+    /// Format to convert into, instead of asking
+    ///
+    /// `lab-d65` and `lch-d65` are not CSS: browsers only read `lab()` and `lch()` relative to
+    /// D50.
+    #[arg(long, value_parser = parse_target_format)]
+    pub to: Option<TargetFormat>,
+    /// Write to stdout without any prompt nor clipboard, reading stdin when there is no other
+    /// input
+    #[arg(long)]
+    pub print: bool,
+    /// Print every format, the detected one, the name and the contrast as JSON, as `--print`
+    /// does
+    #[arg(long, conflicts_with = "to")]
+    pub json: bool,
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct MixArgs {
+    #[arg(value_parser = parse_color)]
+    pub color: Color,
+    #[arg(value_parser = parse_color)]
+    pub other: Color,
+    /// How much of the second color goes into the mix, from 0 to 1
+    #[arg(long, value_parser = parse_amount, default_value_t = 0.5)]
+    pub amount: f64,
+    /// Space the colors are interpolated in
+    #[arg(long = "in", value_parser = parse_mix_space, default_value = "oklab")]
+    pub space: MixSpace,
+    /// Format of the result
+    #[arg(long, value_parser = parse_target_format, default_value = "hex")]
+    pub to: TargetFormat,
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct PaletteArgs {
+    #[arg(value_parser = parse_color)]
+    pub color: Color,
+    /// Scheme built around the color
+    #[arg(long, value_parser = parse_harmony, default_value = "complementary")]
+    pub harmony: Harmony,
+    /// Build a ramp of this many shades of the color instead
+    #[arg(long, conflicts_with = "harmony")]
+    pub shades: Option<usize>,
+    /// Format of the colors
+    #[arg(long, value_parser = parse_target_format, default_value = "hex")]
+    pub to: TargetFormat,
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct BatchArgs {
+    /// File holding one color per line, stdin when missing or `-`
+    pub file: Option<PathBuf>,
+    /// Format the colors are converted into
+    #[arg(long, value_parser = parse_target_format)]
+    pub to: TargetFormat,
+    /// What becomes of lines holding no color: skip, fail or echo
+    #[arg(long, value_parser = parse_error_policy, default_value = "skip")]
+    pub on_error: ErrorPolicy,
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct RewriteArgs {
+    /// Format the literals are converted into
+    #[arg(long, value_parser = parse_target_format)]
+    pub to: TargetFormat,
+    /// Only rewrite literals in this notation (hex, rgb, named, hex-literal...), can be repeated
+    #[arg(long)]
+    pub from: Vec<String>,
+    /// Print a unified diff instead of writing the files
+    #[arg(long, conflicts_with = "check")]
+    pub dry_run: bool,
+    /// Exit with 1 when a file would change, without writing it
+    #[arg(long)]
+    pub check: bool,
+    /// Files and directories to rewrite
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct SnapArgs {
+    /// File listing the palette colors, one per line
+    #[arg(long)]
+    pub palette: PathBuf,
+    /// Largest difference of a drifting color in deltaE OK, whose scale is 100 times smaller than
+    /// the CIE deltaE one: 0.02 is barely visible, as a CIE deltaE of about 2
+    #[arg(long, value_parser = parse_tolerance, default_value_t = DEFAULT_TOLERANCE)]
+    pub tolerance: f64,
+    /// Replace the drifting colors with the palette value
+    #[arg(long)]
+    pub write: bool,
+    /// Replace them with the palette token instead
+    #[arg(long, requires = "write")]
+    pub token: bool,
+    /// Files and directories to check
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+}
+
+fn parse_color(value: &str) -> Result<Color, String> {
+    Color::try_parse(value.to_string()).map_err(|error| error.to_string())
+}
+
+fn parse_target_format(value: &str) -> Result<TargetFormat, String> {
+    TargetFormat::from_name(value).ok_or_else(|| {
+        let names: Vec<String> = TargetFormat::all()
+            .iter()
+            .map(|format| format.name())
+            .collect();
+        format!("expected one of {}", names.join(", "))
+    })
+}
+
+fn parse_amount(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(amount) if (0.0..=1.0).contains(&amount) => Ok(amount),
+        _ => Err(String::from("expected a number from 0 to 1")),
+    }
+}
+
+fn parse_tolerance(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(tolerance) if tolerance.is_finite() && tolerance >= 0.0 => Ok(tolerance),
+        _ => Err(String::from(
+            "expected a deltaE OK difference, such as 0.02",
+        )),
+    }
+}
+
+fn parse_gamut_mapping(value: &str) -> Result<GamutMapping, String> {
+    match value {
+        "clip" => Ok(GamutMapping::Clip),
+        "perceptual" => Ok(GamutMapping::Perceptual),
+        _ => Err(String::from("expected clip or perceptual")),
+    }
+}
+
+fn parse_black_generation(value: &str) -> Result<BlackGeneration, String> {
+    let (name, level) = match value.split_once(':') {
+        Some((name, level)) => (name, Some(level)),
+        None => (value, None),
+    };
+    match (name, level) {
+        ("naive", None) => Ok(BlackGeneration::Naive),
+        ("naive", Some(_)) => Err(String::from("naive takes no level")),
+        ("ucr", level) => Ok(BlackGeneration::UnderColorRemoval {
+            start: parse_level(level.unwrap_or("0.5"))?,
+        }),
+        ("gcr", level) => Ok(BlackGeneration::GrayComponentReplacement {
+            amount: parse_level(level.unwrap_or("0.5"))?,
+        }),
+        _ => Err(String::from("expected naive, ucr:<start> or gcr:<amount>")),
+    }
+}
+
+/// Level of a black generation, from 0 to 1
+fn parse_level(level: &str) -> Result<f64, String> {
+    match level.parse::<f64>() {
+        Ok(number) if (0.0..=1.0).contains(&number) => Ok(number),
+        _ => Err(format!("{} is not a number from 0 to 1", level)),
+    }
+}
+
+fn parse_mix_space(value: &str) -> Result<MixSpace, String> {
+    MixSpace::from_name(value).ok_or_else(|| {
+        let names = MixSpace::ALL.map(|space| space.name());
+        format!("expected one of {}", names.join(", "))
+    })
+}
+
+fn parse_harmony(value: &str) -> Result<Harmony, String> {
+    Harmony::from_name(value).ok_or_else(|| {
+        let names = Harmony::ALL.map(|harmony| harmony.name());
+        format!("expected one of {}", names.join(", "))
+    })
+}
+
+fn parse_error_policy(value: &str) -> Result<ErrorPolicy, String> {
+    ErrorPolicy::from_name(value).ok_or_else(|| {
+        let names = ErrorPolicy::ALL.map(|policy| policy.name());
+        format!("expected one of {}", names.join(", "))
+    })
+}
+
+fn parse_backend_choice(value: &str) -> Result<BackendChoice, String> {
+    BackendChoice::from_name(value).ok_or_else(|| {
+        let names = BackendChoice::ALL.map(|choice| choice.name());
+        format!("expected one of {}", names.join(", "))
+    })
+}
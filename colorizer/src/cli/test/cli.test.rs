@@ -0,0 +1,154 @@
+#[cfg(test)]
+pub mod tests {
+    use clap::{CommandFactory, Parser};
+
+    use colorizer::color::{Color, Harmony, MixSpace, RGB, TargetFormat};
+
+    use crate::{
+        batch::ErrorPolicy,
+        cli::{Cli, Command},
+    };
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_cli_convert_without_subcommand() {
+        let cli =
+            Cli::try_parse_from(["colorizer", "-i", "#fff", "--to", "hsl", "-p", "2"]).unwrap();
+        assert!(cli.command.is_none());
+        assert_eq!(cli.convert.input.as_deref(), Some("#fff"));
+        assert_eq!(cli.convert.to, Some(TargetFormat::Hsl));
+        assert_eq!(cli.convert.format.precision, 2);
+
+        let cli = Cli::try_parse_from(["colorizer", "convert", "red", "--print"]).unwrap();
+        match cli.command {
+            Some(Command::Convert { color, args }) => {
+                assert_eq!(color.as_deref(), Some("red"));
+                assert!(args.print);
+            }
+            _ => panic!("expected the convert subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_cli_subcommands() {
+        let cli = Cli::try_parse_from([
+            "colorizer",
+            "mix",
+            "red",
+            "#00f",
+            "--amount",
+            "0.25",
+            "--in",
+            "oklch",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Mix(args)) => {
+                assert_eq!(args.other, Color::from(RGB::new(0.0, 0.0, 255.0)));
+                assert_eq!(args.amount, 0.25);
+                assert_eq!(args.space, MixSpace::OkLch);
+                assert_eq!(args.to, TargetFormat::Hex);
+            }
+            _ => panic!("expected the mix subcommand"),
+        }
+
+        let cli =
+            Cli::try_parse_from(["colorizer", "palette", "teal", "--harmony", "triadic"]).unwrap();
+        match cli.command {
+            Some(Command::Palette(args)) => {
+                assert_eq!(args.harmony, Harmony::Triadic);
+                assert_eq!(args.shades, None);
+            }
+            _ => panic!("expected the palette subcommand"),
+        }
+
+        let cli =
+            Cli::try_parse_from(["colorizer", "batch", "--to", "oklch", "--on-error", "echo"])
+                .unwrap();
+        match cli.command {
+            Some(Command::Batch(args)) => {
+                assert_eq!(args.file, None);
+                assert_eq!(args.on_error, ErrorPolicy::Echo);
+            }
+            _ => panic!("expected the batch subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_cli_rejects_invalid_values() {
+        let invalid = [
+            vec!["colorizer", "mix", "red", "nope"],
+            vec!["colorizer", "mix", "red", "blue", "--amount", "2"],
+            vec!["colorizer", "contrast", "red"],
+            vec!["colorizer", "-i", "red", "--to", "nope"],
+            vec!["colorizer", "-i", "red", "-c"],
+            vec![
+                "colorizer",
+                "palette",
+                "red",
+                "--harmony",
+                "triadic",
+                "--shades",
+                "3",
+            ],
+            vec!["colorizer", "rewrite", "--to", "hex"],
+            vec!["colorizer", "-i", "red", "--black-generation", "gcr:2"],
+            vec![
+                "colorizer",
+                "snap",
+                "--palette",
+                "p.css",
+                "--tolerance",
+                "-1",
+                ".",
+            ],
+            vec![
+                "colorizer",
+                "snap",
+                "--palette",
+                "p.css",
+                "--tolerance",
+                "NaN",
+                ".",
+            ],
+            vec![
+                "colorizer",
+                "snap",
+                "--palette",
+                "p.css",
+                "--tolerance",
+                "inf",
+                ".",
+            ],
+            vec!["colorizer", "-i", "red", "--black-generation", "ucr:-0.5"],
+            vec!["colorizer", "-i", "red", "--black-generation", "naive:2"],
+            vec!["colorizer", "-i", "red", "--black-generation", "foo:x"],
+            vec!["colorizer", "batch", "colors.txt"],
+            vec!["colorizer", "batch", "--to", "hex", "--on-error", "ignore"],
+            vec!["colorizer", "completions", "nope"],
+        ];
+        for args in invalid {
+            assert!(
+                Cli::try_parse_from(&args).is_err(),
+                "{:?} should fail",
+                args
+            );
+        }
+    }
+
+    #[test]
+    fn test_cli_black_generation_errors() {
+        let message = |value: &str| {
+            Cli::try_parse_from(["colorizer", "-i", "red", "--black-generation", value])
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(message("naive:2").contains("naive takes no level"));
+        assert!(message("foo:x").contains("expected naive, ucr:<start> or gcr:<amount>"));
+        assert!(message("gcr:x").contains("x is not a number from 0 to 1"));
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    io::{self, Write},
+    process::{Command, Stdio},
+};
+
+use crate::clipboard::ClipboardBackend;
+
+/// Clipboard reached through the command line tools of a display server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandClipboard {
+    pub name: &'static str,
+    /// Program and arguments copying their stdin
+    pub copy: &'static [&'static str],
+    /// Program and arguments writing the clipboard to their stdout
+    pub paste: &'static [&'static str],
+}
+
+impl CommandClipboard {
+    pub const WL_COPY: CommandClipboard = CommandClipboard {
+        name: "wl-copy",
+        copy: &["wl-copy"],
+        paste: &["wl-paste", "--no-newline"],
+    };
+    pub const XCLIP: CommandClipboard = CommandClipboard {
+        name: "xclip",
+        copy: &["xclip", "-selection", "clipboard"],
+        paste: &["xclip", "-selection", "clipboard", "-o"],
+    };
+    pub const XSEL: CommandClipboard = CommandClipboard {
+        name: "xsel",
+        copy: &["xsel", "--clipboard", "--input"],
+        paste: &["xsel", "--clipboard", "--output"],
+    };
+}
+
+impl ClipboardBackend for CommandClipboard {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn copy(&mut self, text: &str) -> Result<(), String> {
+        let (program, arguments) = split_command(self.copy)?;
+        let mut child = Command::new(program)
+            .args(arguments)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|error| spawn_error(program, error))?;
+        // The pipe is closed when dropped, which tells the command the text is complete
+        child
+            .stdin
+            .take()
+            .ok_or_else(|| format!("{} has no stdin", program))?
+            .write_all(text.as_bytes())
+            .map_err(|error| error.to_string())?;
+        let status = child.wait().map_err(|error| error.to_string())?;
+        match status.success() {
+            true => Ok(()),
+            false => Err(format!("{} failed with {}", program, status)),
+        }
+    }
+
+    fn paste(&mut self) -> Result<String, String> {
+        let (program, arguments) = split_command(self.paste)?;
+        let output = Command::new(program)
+            .args(arguments)
+            .stderr(Stdio::null())
+            .output()
+            .map_err(|error| spawn_error(program, error))?;
+        if !output.status.success() {
+            return Err(format!("{} failed with {}", program, output.status));
+        }
+        String::from_utf8(output.stdout).map_err(|error| error.to_string())
+    }
+}
+
+/// Program and arguments of `command`
+fn split_command<'a>(command: &'a [&'a str]) -> Result<(&'a str, &'a [&'a str]), String> {
+    command
+        .split_first()
+        .map(|(program, arguments)| (*program, arguments))
+        .ok_or_else(|| String::from("no command to run"))
+}
+
+fn spawn_error(program: &str, error: io::Error) -> String {
+    match error.kind() {
+        io::ErrorKind::NotFound => format!("{} is not installed", program),
+        _ => format!("{} could not be run ({})", program, error),
+    }
+}
@@ -0,0 +1,30 @@
+use crate::clipboard::ClipboardBackend;
+
+/// Clipboard kept in memory, failing when unavailable, for tests
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryClipboard {
+    pub text: Option<String>,
+    pub unavailable: bool,
+}
+
+impl ClipboardBackend for MemoryClipboard {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    fn copy(&mut self, text: &str) -> Result<(), String> {
+        if self.unavailable {
+            return Err(String::from("unavailable"));
+        }
+        self.text = Some(text.to_string());
+        Ok(())
+    }
+
+    fn paste(&mut self) -> Result<String, String> {
+        match (&self.text, self.unavailable) {
+            (Some(text), false) => Ok(text.clone()),
+            (None, false) => Err(String::from("empty")),
+            (_, true) => Err(String::from("unavailable")),
+        }
+    }
+}
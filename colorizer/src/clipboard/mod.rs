@@ -0,0 +1,214 @@
+#[cfg(test)]
+#[path = "./test/clipboard.test.rs"]
+mod test;
+
+mod command;
+#[cfg(test)]
+mod memory;
+#[cfg(feature = "clipboard")]
+mod native;
+mod osc52;
+
+use std::{env, fmt::Display};
+
+use colored::Colorize;
+
+use crate::prompt;
+
+pub use command::CommandClipboard;
+#[cfg(test)]
+pub use memory::MemoryClipboard;
+#[cfg(feature = "clipboard")]
+pub use native::NativeClipboard;
+pub use osc52::Osc52Clipboard;
+
+/// Way of reaching a clipboard
+pub trait ClipboardBackend {
+    /// Name of the backend, as given to `--clipboard-backend`
+    fn name(&self) -> &'static str;
+    /// Replaces the clipboard content with `text`, or tells why it can't
+    fn copy(&mut self, text: &str) -> Result<(), String>;
+    /// Text held by the clipboard, or why it can't be read
+    fn paste(&mut self) -> Result<String, String>;
+}
+
+/// Clipboard backend asked for on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendChoice {
+    /// The first one that works, see [`candidates`]
+    Auto,
+    /// The system clipboard through the `arboard` crate, with the `clipboard` feature
+    Native,
+    /// OSC 52 escape sequences, which the terminal turns into a copy on the local machine
+    Osc52,
+    WlCopy,
+    Xclip,
+    Xsel,
+}
+
+impl BackendChoice {
+    pub const ALL: [BackendChoice; 6] = [
+        BackendChoice::Auto,
+        BackendChoice::Native,
+        BackendChoice::Osc52,
+        BackendChoice::WlCopy,
+        BackendChoice::Xclip,
+        BackendChoice::Xsel,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BackendChoice::Auto => "auto",
+            BackendChoice::Native => "native",
+            BackendChoice::Osc52 => "osc52",
+            BackendChoice::WlCopy => "wl-copy",
+            BackendChoice::Xclip => "xclip",
+            BackendChoice::Xsel => "xsel",
+        }
+    }
+
+    /// Finds a backend by its name, ignoring case
+    pub fn from_name(name: &str) -> Option<BackendChoice> {
+        Self::ALL
+            .into_iter()
+            .find(|choice| choice.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl Display for BackendChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// What automatic backend selection looks at
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Session {
+    /// Logged in over SSH, where the clipboard of this machine is not the user's one
+    pub remote: bool,
+    pub wayland: bool,
+    pub x11: bool,
+}
+
+impl Session {
+    /// The session of the running process, read from its environment variables
+    pub fn current() -> Self {
+        let set = |name: &str| env::var_os(name).is_some_and(|value| !value.is_empty());
+        Session {
+            remote: set("SSH_TTY") || set("SSH_CONNECTION"),
+            wayland: set("WAYLAND_DISPLAY"),
+            x11: set("DISPLAY"),
+        }
+    }
+}
+
+/// Backends to try in order for `choice`
+///
+/// Automatic selection goes through OSC 52 first over SSH, then the native clipboard and the
+/// commands of the running display server. Locally OSC 52 is only used when asked for: writing
+/// the sequence succeeds whether or not the terminal supports it, which would hide that nothing
+/// was copied.
+pub fn candidates(choice: BackendChoice, session: Session) -> Vec<BackendChoice> {
+    if choice != BackendChoice::Auto {
+        return vec![choice];
+    }
+    let mut candidates = vec![];
+    if session.remote {
+        candidates.push(BackendChoice::Osc52);
+    }
+    if cfg!(feature = "clipboard") {
+        candidates.push(BackendChoice::Native);
+    }
+    if session.wayland {
+        candidates.push(BackendChoice::WlCopy);
+    }
+    if session.x11 {
+        candidates.extend([BackendChoice::Xclip, BackendChoice::Xsel]);
+    }
+    candidates
+}
+
+/// The backend of `choice`, which must not be [`BackendChoice::Auto`]
+pub fn backend(choice: BackendChoice) -> Result<Box<dyn ClipboardBackend>, String> {
+    match choice {
+        BackendChoice::Auto => Err(String::from("auto is not a backend")),
+        #[cfg(feature = "clipboard")]
+        BackendChoice::Native => Ok(Box::<NativeClipboard>::default()),
+        #[cfg(not(feature = "clipboard"))]
+        BackendChoice::Native => Err(String::from("built without the clipboard feature")),
+        BackendChoice::Osc52 => Ok(Box::new(Osc52Clipboard)),
+        BackendChoice::WlCopy => Ok(Box::new(CommandClipboard::WL_COPY)),
+        BackendChoice::Xclip => Ok(Box::new(CommandClipboard::XCLIP)),
+        BackendChoice::Xsel => Ok(Box::new(CommandClipboard::XSEL)),
+    }
+}
+
+/// Copies `text` with the first of `backends` that works, returning its name, or every failure
+pub fn copy_with(
+    backends: &mut [Box<dyn ClipboardBackend>],
+    text: &str,
+) -> Result<&'static str, String> {
+    let mut failures = vec![];
+    for backend in backends.iter_mut() {
+        match backend.copy(text) {
+            Ok(_) => return Ok(backend.name()),
+            Err(reason) => failures.push(format!("{}: {}", backend.name(), reason)),
+        }
+    }
+    Err(failure_message(failures))
+}
+
+/// Reads the clipboard with the first of `backends` that works, or returns every failure
+pub fn paste_with(backends: &mut [Box<dyn ClipboardBackend>]) -> Result<String, String> {
+    let mut failures = vec![];
+    for backend in backends.iter_mut() {
+        match backend.paste() {
+            Ok(text) => return Ok(text),
+            Err(reason) => failures.push(format!("{}: {}", backend.name(), reason)),
+        }
+    }
+    Err(failure_message(failures))
+}
+
+/// Copies `text` to the clipboard, printing it to stdout instead when no backend works, such as
+/// on headless machines
+pub fn set_clipboard(text: &str, choice: BackendChoice) {
+    let mut backends = backends(choice);
+    match copy_with(&mut backends, text) {
+        // On X11 the natively copied text only lasts as long as its backend, which is kept until
+        // the prompt is acknowledged so that it can be pasted meanwhile
+        Ok(_) => prompt::acknowledge(&format!(
+            "{} Copied {} to clipboard!",
+            "✔ ".truecolor(0, 240, 0),
+            text
+        )),
+        Err(reason) => {
+            eprintln!(
+                "{} {}, printing the color instead",
+                "⚠".truecolor(240, 200, 0),
+                reason
+            );
+            println!("{}", text);
+        }
+    }
+}
+
+/// Text held by the clipboard, or why no backend could read it
+pub fn read_clipboard(choice: BackendChoice) -> Result<String, String> {
+    paste_with(&mut backends(choice))
+}
+
+/// Backends of the candidates for `choice` in the current session
+fn backends(choice: BackendChoice) -> Vec<Box<dyn ClipboardBackend>> {
+    candidates(choice, Session::current())
+        .into_iter()
+        .filter_map(|candidate| backend(candidate).ok())
+        .collect()
+}
+
+fn failure_message(failures: Vec<String>) -> String {
+    match failures.is_empty() {
+        true => String::from("No clipboard available"),
+        false => format!("No clipboard available ({})", failures.join(", ")),
+    }
+}
@@ -0,0 +1,40 @@
+use arboard::Clipboard;
+
+use crate::clipboard::ClipboardBackend;
+
+/// System clipboard through `arboard`, which needs a display server on Linux
+///
+/// On X11 the copied text is served by the process itself, so the handle is kept after a copy
+/// and the text stays available for as long as the backend lives.
+#[derive(Default)]
+pub struct NativeClipboard {
+    clipboard: Option<Clipboard>,
+}
+
+impl NativeClipboard {
+    fn clipboard(&mut self) -> Result<&mut Clipboard, String> {
+        let clipboard = match self.clipboard.take() {
+            Some(clipboard) => clipboard,
+            None => Clipboard::new().map_err(|error| error.to_string())?,
+        };
+        Ok(self.clipboard.insert(clipboard))
+    }
+}
+
+impl ClipboardBackend for NativeClipboard {
+    fn name(&self) -> &'static str {
+        "native"
+    }
+
+    fn copy(&mut self, text: &str) -> Result<(), String> {
+        self.clipboard()?
+            .set_text(text)
+            .map_err(|error| error.to_string())
+    }
+
+    fn paste(&mut self) -> Result<String, String> {
+        self.clipboard()?
+            .get_text()
+            .map_err(|error| error.to_string())
+    }
+}
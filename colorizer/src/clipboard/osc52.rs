@@ -0,0 +1,44 @@
+use std::{env, fs::OpenOptions, io::Write};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::clipboard::ClipboardBackend;
+
+/// Clipboard of the terminal the user types in, reached with OSC 52 escape sequences
+///
+/// The terminal does the copy on its own machine, so this works over SSH. It can't be read, most
+/// terminals refusing to answer clipboard queries.
+pub struct Osc52Clipboard;
+
+impl Osc52Clipboard {
+    /// Escape sequence copying `text`, wrapped for tmux to pass it on to the terminal
+    pub fn sequence(text: &str, tmux: bool) -> String {
+        let sequence = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+        match tmux {
+            true => format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b")),
+            false => sequence,
+        }
+    }
+}
+
+impl ClipboardBackend for Osc52Clipboard {
+    fn name(&self) -> &'static str {
+        "osc52"
+    }
+
+    /// Writes the sequence to the controlling terminal, which stdout may not be
+    fn copy(&mut self, text: &str) -> Result<(), String> {
+        let mut terminal = OpenOptions::new()
+            .write(true)
+            .open("/dev/tty")
+            .map_err(|error| format!("no terminal ({})", error))?;
+        let sequence = Self::sequence(text, env::var_os("TMUX").is_some());
+        terminal
+            .write_all(sequence.as_bytes())
+            .map_err(|error| error.to_string())
+    }
+
+    fn paste(&mut self) -> Result<String, String> {
+        Err(String::from("the terminal clipboard can't be read"))
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::clipboard::{
+        BackendChoice, ClipboardBackend, CommandClipboard, MemoryClipboard, Osc52Clipboard,
+        Session, candidates, copy_with, paste_with,
+    };
+
+    fn memory(text: Option<&str>, unavailable: bool) -> Box<dyn ClipboardBackend> {
+        Box::new(MemoryClipboard {
+            text: text.map(String::from),
+            unavailable,
+        })
+    }
+
+    #[test]
+    fn test_candidates() {
+        let native: &[BackendChoice] = match cfg!(feature = "clipboard") {
+            true => &[BackendChoice::Native],
+            false => &[],
+        };
+        let local = Session {
+            remote: false,
+            wayland: false,
+            x11: true,
+        };
+        let expected = [native, &[BackendChoice::Xclip, BackendChoice::Xsel]].concat();
+        assert_eq!(candidates(BackendChoice::Auto, local), expected);
+        // Headless, nothing is tried and the color gets printed
+        assert_eq!(
+            candidates(BackendChoice::Auto, Session::default()),
+            native.to_vec()
+        );
+
+        let remote = Session {
+            remote: true,
+            wayland: true,
+            x11: false,
+        };
+        let expected = [&[BackendChoice::Osc52], native, &[BackendChoice::WlCopy]].concat();
+        assert_eq!(candidates(BackendChoice::Auto, remote), expected);
+
+        assert_eq!(
+            candidates(BackendChoice::Xsel, Session::default()),
+            vec![BackendChoice::Xsel]
+        );
+    }
+
+    #[test]
+    fn test_copy_falls_back() {
+        let mut backends = vec![memory(None, true), memory(None, false)];
+        assert_eq!(copy_with(&mut backends, "#FF0000"), Ok("memory"));
+        assert_eq!(backends[1].paste(), Ok(String::from("#FF0000")));
+
+        let mut backends = vec![memory(None, true)];
+        assert_eq!(
+            copy_with(&mut backends, "#FF0000"),
+            Err(String::from("No clipboard available (memory: unavailable)"))
+        );
+        assert!(copy_with(&mut [], "#FF0000").is_err());
+    }
+
+    #[test]
+    fn test_paste_falls_back() {
+        let mut backends = vec![memory(None, false), memory(Some("teal"), false)];
+        assert_eq!(paste_with(&mut backends), Ok(String::from("teal")));
+        assert!(paste_with(&mut [memory(Some("teal"), true)]).is_err());
+    }
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(
+            Osc52Clipboard::sequence("#FF0000", false),
+            "\x1b]52;c;I0ZGMDAwMA==\x07"
+        );
+        assert_eq!(
+            Osc52Clipboard::sequence("red", true),
+            "\x1bPtmux;\x1b\x1b]52;c;cmVk\x07\x1b\\"
+        );
+        assert!(Osc52Clipboard.paste().is_err());
+    }
+
+    #[test]
+    fn test_command_not_installed() {
+        let mut missing = CommandClipboard {
+            name: "missing",
+            copy: &["colorizer-missing-clipboard-tool"],
+            paste: &["colorizer-missing-clipboard-tool"],
+        };
+        assert_eq!(
+            missing.copy("red"),
+            Err(String::from(
+                "colorizer-missing-clipboard-tool is not installed"
+            ))
+        );
+        assert!(missing.paste().is_err());
+    }
+
+    #[test]
+    fn test_backend_choice_names() {
+        for choice in BackendChoice::ALL {
+            assert_eq!(BackendChoice::from_name(choice.name()), Some(choice));
+        }
+        assert_eq!(
+            BackendChoice::from_name("OSC52"),
+            Some(BackendChoice::Osc52)
+        );
+        assert_eq!(BackendChoice::from_name("pbcopy"), None);
+    }
+}
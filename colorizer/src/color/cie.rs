@@ -0,0 +1,156 @@
+use crate::color::WhitePoint;
+
+pub type Vector = [f64; 3];
+pub type Matrix = [[f64; 3]; 3];
+
+/// Bradford chromatic adaptation between the D65 and D50 white points, one the exact inverse of
+/// the other so round trips don't drift
+const D65_TO_D50: Matrix = [
+    [
+        1.0479297925449969,
+        0.022946870601609652,
+        -0.05019226628920524,
+    ],
+    [
+        0.02962780877005599,
+        0.9904344267538799,
+        -0.017073799063418826,
+    ],
+    [
+        -0.009243040646204504,
+        0.015055191490298152,
+        0.752131635446103,
+    ],
+];
+const D50_TO_D65: Matrix = [
+    [
+        0.9554732210842749,
+        -0.023098121198083365,
+        0.06323759222888897,
+    ],
+    [
+        -0.02836977599265651,
+        1.0099955090942563,
+        0.021034239593789694,
+    ],
+    [
+        0.01230980029640259,
+        -0.020500630395229483,
+        1.3299105980791852,
+    ],
+];
+
+/// CIE Lab constants, `216 / 24389` and `24389 / 27`
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+pub fn multiply(matrix: &Matrix, vector: Vector) -> Vector {
+    [
+        matrix[0][0] * vector[0] + matrix[0][1] * vector[1] + matrix[0][2] * vector[2],
+        matrix[1][0] * vector[0] + matrix[1][1] * vector[1] + matrix[1][2] * vector[2],
+        matrix[2][0] * vector[0] + matrix[2][1] * vector[1] + matrix[2][2] * vector[2],
+    ]
+}
+
+impl WhitePoint {
+    /// XYZ coordinates of the white point, normalized to `Y = 1`
+    ///
+    /// D50 is taken as the Bradford adaptation of D65, so sRGB white stays neutral in both.
+    pub fn xyz(&self) -> Vector {
+        let (x, y) = (0.3127, 0.3290);
+        let d65 = [x / y, 1.0, (1.0 - x - y) / y];
+        match self {
+            WhitePoint::D50 => multiply(&D65_TO_D50, d65),
+            WhitePoint::D65 => d65,
+        }
+    }
+}
+
+/// Removes the sRGB transfer function, keeping the sign of out of gamut values
+pub fn srgb_to_linear(channel: f64) -> f64 {
+    let magnitude = channel.abs();
+    if magnitude <= 0.04045 {
+        return channel / 12.92;
+    }
+    channel.signum() * ((magnitude + 0.055) / 1.055).powf(2.4)
+}
+
+/// Applies the sRGB transfer function, keeping the sign of out of gamut values
+pub fn linear_to_srgb(channel: f64) -> f64 {
+    let magnitude = channel.abs();
+    if magnitude <= 0.0031308 {
+        return channel * 12.92;
+    }
+    channel.signum() * (1.055 * magnitude.powf(1.0 / 2.4) - 0.055)
+}
+
+/// Adapts XYZ coordinates from one white point to another
+pub fn adapt(xyz: Vector, from: WhitePoint, to: WhitePoint) -> Vector {
+    match (from, to) {
+        (WhitePoint::D65, WhitePoint::D50) => multiply(&D65_TO_D50, xyz),
+        (WhitePoint::D50, WhitePoint::D65) => multiply(&D50_TO_D65, xyz),
+        _ => xyz,
+    }
+}
+
+/// CIE XYZ to `[L, a, b]`, with `L` going from `0` to `100`
+pub fn xyz_to_lab(xyz: Vector, white: WhitePoint) -> Vector {
+    let white = white.xyz();
+    let f = |value: f64| {
+        if value > EPSILON {
+            value.cbrt()
+        } else {
+            (KAPPA * value + 16.0) / 116.0
+        }
+    };
+    let fx = f(xyz[0] / white[0]);
+    let fy = f(xyz[1] / white[1]);
+    let fz = f(xyz[2] / white[2]);
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// `[L, a, b]` to CIE XYZ
+pub fn lab_to_xyz(lab: Vector, white: WhitePoint) -> Vector {
+    let white = white.xyz();
+    let fy = (lab[0] + 16.0) / 116.0;
+    let fx = lab[1] / 500.0 + fy;
+    let fz = fy - lab[2] / 200.0;
+
+    let inverse = |value: f64| {
+        let cube = value.powi(3);
+        if cube > EPSILON {
+            cube
+        } else {
+            (116.0 * value - 16.0) / KAPPA
+        }
+    };
+    let y = if lab[0] > KAPPA * EPSILON {
+        fy.powi(3)
+    } else {
+        lab[0] / KAPPA
+    };
+
+    [inverse(fx) * white[0], y * white[1], inverse(fz) * white[2]]
+}
+
+/// Chroma under which a color is treated as achromatic when converting to polar coordinates
+const ACHROMATIC_THRESHOLD: f64 = 1e-6;
+
+/// Rectangular `[L, a, b]` to polar `[L, C, h]`, `h` in degrees
+///
+/// Grays get a hue of `0`, the rounding noise on their axes would make it arbitrary.
+pub fn to_polar(lab: Vector) -> Vector {
+    let chroma = lab[1].hypot(lab[2]);
+    if chroma < ACHROMATIC_THRESHOLD {
+        return [lab[0], chroma, 0.0];
+    }
+    let hue = lab[2].atan2(lab[1]).to_degrees().rem_euclid(360.0);
+    [lab[0], chroma, hue]
+}
+
+/// Polar `[L, C, h]` to rectangular `[L, a, b]`
+pub fn to_rectangular(lch: Vector) -> Vector {
+    let hue = lch[2].to_radians();
+    [lch[0], lch[1] * hue.cos(), lch[1] * hue.sin()]
+}
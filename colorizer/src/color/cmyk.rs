@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, BlackGeneration, CMYK, Color, ColorFormat, Gamut, GamutMapping, ParseError,
+        Percentage, RGB,
+        format::{format_number, write_function, write_modern_function},
+        parser::{parse_alpha, parse_channel, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+impl CMYK {
+    pub fn new(c: impl Into<f64>, m: impl Into<f64>, y: impl Into<f64>, k: impl Into<f64>) -> Self {
+        Self(
+            Percentage::new(c.into()),
+            Percentage::new(m.into()),
+            Percentage::new(y.into()),
+            Percentage::new(k.into()),
+            1.0,
+        )
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.4 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Separates `color` into inks, printing its gray component with black as `generation` says
+    ///
+    /// Colors outside of sRGB are mapped into it first, the same as for `RGB`.
+    pub fn from_color(color: Color, generation: BlackGeneration) -> Self {
+        let color = color.mapped_to_gamut(Gamut::Srgb, GamutMapping::Perceptual);
+        let cmy = [color.0, color.1, color.2].map(|channel| 1.0 - channel.clamp(0.0, 1.0));
+        let gray = cmy[0].min(cmy[1]).min(cmy[2]);
+
+        let k = match generation {
+            BlackGeneration::Naive => gray,
+            BlackGeneration::UnderColorRemoval { start } if gray > start => {
+                gray * (gray - start) / (1.0 - start)
+            }
+            BlackGeneration::UnderColorRemoval { .. } => 0.0,
+            BlackGeneration::GrayComponentReplacement { amount } => gray * amount.clamp(0.0, 1.0),
+        };
+        if k >= 1.0 {
+            return Self::new(0, 0, 0, 100).with_alpha(color.3);
+        }
+
+        let [c, m, y] = cmy.map(|ink| (ink - k) / (1.0 - k) * 100.0);
+        Self::new(c, m, y, k * 100.0).with_alpha(color.3)
+    }
+}
+
+impl Display for CMYK {
+    /// Writes `cmyk(c%, m%, y%, k%)`, or `device-cmyk(c% m% y% k%)` with the alternate flag
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [&self.0, &self.1, &self.2, &self.3]
+            .map(|ink| format!("{}%", format_number(ink.to_f64(), precision)));
+        if f.alternate() {
+            return write_modern_function(f, "device-cmyk", &channels, self.4);
+        }
+        write_function(f, "cmyk", &channels, self.4)
+    }
+}
+
+impl PartialEq for CMYK {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+            && self.1 == other.1
+            && self.2 == other.2
+            && self.3 == other.3
+            && (self.4 - other.4).abs() < EPSILON
+    }
+}
+
+impl TryFrom<&str> for CMYK {
+    type Error = ParseError;
+
+    /// Parses `cmyk[a](c, m, y, k[, a])` or `device-cmyk(c m y k[ / a])`
+    ///
+    /// Inks are percentages, with the percent sign being optional in `cmyk()`. As in CSS,
+    /// `device-cmyk()` takes plain numbers from `0` to `1` instead.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let value = value.trim();
+        let components = split_function(
+            value,
+            ColorFormat::Cmyk,
+            &["cmyk", "cmyka", "device-cmyk"],
+            4..=5,
+        )
+        .map_err(|error| error.shifted(offset))?;
+        let max = match value.to_lowercase().starts_with("device-cmyk") {
+            true => 1.0,
+            false => 100.0,
+        };
+
+        let mut inks = [0.0; 4];
+        for (index, name) in ["cyan", "magenta", "yellow", "black"]
+            .into_iter()
+            .enumerate()
+        {
+            inks[index] = parse_channel(&components[index], ColorFormat::Cmyk, name, max)
+                .map_err(|error| error.shifted(offset))?
+                / max
+                * 100.0;
+        }
+        let alpha = parse_alpha(&components, 4, ColorFormat::Cmyk)
+            .map_err(|error| error.shifted(offset))?;
+
+        Ok(Self::new(inks[0], inks[1], inks[2], inks[3]).with_alpha(alpha))
+    }
+}
+
+impl TryFrom<String> for CMYK {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Color> for CMYK {
+    /// Uses the naive black generation, the one CSS `device-cmyk()` describes
+    fn from(color: Color) -> Self {
+        CMYK::from_color(color, BlackGeneration::Naive)
+    }
+}
+
+impl From<CMYK> for Color {
+    fn from(color: CMYK) -> Self {
+        let k = color.3.to_f64() / 100.0;
+        let [r, g, b] = [color.0, color.1, color.2]
+            .map(|ink| 1.0 - (ink.to_f64() / 100.0 * (1.0 - k) + k).min(1.0));
+        Color(r, g, b, color.4)
+    }
+}
+
+impl From<RGB> for CMYK {
+    fn from(color: RGB) -> Self {
+        CMYK::from(Color::from(color))
+    }
+}
+
+impl From<CMYK> for RGB {
+    fn from(color: CMYK) -> Self {
+        RGB::from(Color::from(color))
+    }
+}
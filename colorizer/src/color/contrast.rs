@@ -0,0 +1,51 @@
+use crate::color::{Color, LinearRgb};
+
+/// Minimum WCAG 2 contrast ratios of levels AA and AAA, for normal and large text
+pub const WCAG_AA: f64 = 4.5;
+pub const WCAG_AA_LARGE: f64 = 3.0;
+pub const WCAG_AAA: f64 = 7.0;
+pub const WCAG_AAA_LARGE: f64 = 4.5;
+
+impl Color {
+    /// WCAG 2 relative luminance, from `0.0` for black to `1.0` for white
+    ///
+    /// The color is measured as a display shows it: opaque and clipped to sRGB.
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = LinearRgb::from(self.clone());
+        let [r, g, b] = [linear.0, linear.1, linear.2].map(|channel| channel.clamp(0.0, 1.0));
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// WCAG 2 contrast ratio of the color as text over `background`, from `1.0` to `21.0`
+    ///
+    /// A translucent color is composited over the background first, the background itself is
+    /// taken as opaque.
+    pub fn contrast_ratio(&self, background: &Color) -> f64 {
+        let background = Color(background.0, background.1, background.2, 1.0);
+        let foreground = self.composited_over(&background);
+        let (lighter, darker) = {
+            let a = foreground.relative_luminance();
+            let b = background.relative_luminance();
+            (a.max(b), a.min(b))
+        };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// The color painted over `background` with the source-over operator, in gamma encoded sRGB
+    /// as browsers do
+    pub fn composited_over(&self, background: &Color) -> Color {
+        let alpha = self.3 + background.3 * (1.0 - self.3);
+        if alpha <= 0.0 {
+            return Color(0.0, 0.0, 0.0, 0.0);
+        }
+        let blend = |source: f64, destination: f64| {
+            (source * self.3 + destination * background.3 * (1.0 - self.3)) / alpha
+        };
+        Color(
+            blend(self.0, background.0),
+            blend(self.1, background.1),
+            blend(self.2, background.2),
+            alpha,
+        )
+    }
+}
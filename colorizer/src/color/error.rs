@@ -0,0 +1,168 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    ops::Range,
+};
+
+use crate::color::RgbSpace;
+
+/// Notation a color was written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorFormat {
+    Hex,
+    /// `0xRRGGBB` literal, as written in source code
+    HexLiteral,
+    Rgb,
+    Hsl,
+    Hsv,
+    Hwb,
+    Cmyk,
+    Lab,
+    Lch,
+    OkLab,
+    OkLch,
+    /// `color()` function whose color space is not known yet
+    Color,
+    Xyz,
+    LinearRgb,
+    WideRgb(RgbSpace),
+    YCbCr,
+    Yuv,
+    Yiq,
+    Named,
+}
+
+/// Why a color could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorReason {
+    /// Nothing in the input looks like a color
+    UnknownFormat,
+    /// A functional notation is missing its closing parenthesis
+    MissingParen,
+    /// A functional notation has the wrong number of components
+    ComponentCount {
+        min: usize,
+        max: usize,
+        found: usize,
+    },
+    /// A component is not a number
+    InvalidNumber,
+    /// A component is a number outside of its valid range
+    OutOfRange { value: f64, min: f64, max: f64 },
+    /// A hex color does not have a supported amount of digits
+    BadHexLength(usize),
+    /// A `0x` hex literal does not have a supported amount of digits
+    BadHexLiteralLength(usize),
+    /// A hex color contains something that is not an hex digit
+    InvalidHex,
+    /// A `color()` function names a color space that is not supported
+    UnknownColorSpace,
+    /// A word is not one of the CSS color names
+    UnknownName,
+}
+
+/// Diagnostic of a failed color parse
+///
+/// `span` is the byte range of the offending text inside the parsed input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub format: Option<ColorFormat>,
+    pub component: Option<&'static str>,
+    pub span: Range<usize>,
+    pub reason: ParseErrorReason,
+}
+
+impl ParseError {
+    pub fn new(format: Option<ColorFormat>, span: Range<usize>, reason: ParseErrorReason) -> Self {
+        Self {
+            format,
+            component: None,
+            span,
+            reason,
+        }
+    }
+
+    pub fn with_component(mut self, component: &'static str) -> Self {
+        self.component = Some(component);
+        self
+    }
+
+    /// Moves the span by `offset` bytes, used when the error comes from a slice of a bigger input
+    pub fn shifted(mut self, offset: usize) -> Self {
+        self.span = (self.span.start + offset)..(self.span.end + offset);
+        self
+    }
+}
+
+impl Display for ColorFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorFormat::Hex => write!(f, "hex"),
+            ColorFormat::HexLiteral => write!(f, "hex literal"),
+            ColorFormat::Rgb => write!(f, "rgb()"),
+            ColorFormat::Hsl => write!(f, "hsl()"),
+            ColorFormat::Hsv => write!(f, "hsv()"),
+            ColorFormat::Hwb => write!(f, "hwb()"),
+            ColorFormat::Cmyk => write!(f, "cmyk()"),
+            ColorFormat::Lab => write!(f, "lab()"),
+            ColorFormat::Lch => write!(f, "lch()"),
+            ColorFormat::OkLab => write!(f, "oklab()"),
+            ColorFormat::OkLch => write!(f, "oklch()"),
+            ColorFormat::Color => write!(f, "color()"),
+            ColorFormat::Xyz => write!(f, "color(xyz)"),
+            ColorFormat::LinearRgb => write!(f, "color(srgb-linear)"),
+            ColorFormat::WideRgb(space) => write!(f, "color({})", space),
+            ColorFormat::YCbCr => write!(f, "ycbcr()"),
+            ColorFormat::Yuv => write!(f, "yuv()"),
+            ColorFormat::Yiq => write!(f, "yiq()"),
+            ColorFormat::Named => write!(f, "named"),
+        }
+    }
+}
+
+impl Display for ParseErrorReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorReason::UnknownFormat => write!(f, "no known color format found"),
+            ParseErrorReason::MissingParen => write!(f, "missing closing parenthesis"),
+            ParseErrorReason::ComponentCount { min, max, found } if min == max => {
+                write!(f, "expected {} components, found {}", min, found)
+            }
+            ParseErrorReason::ComponentCount { min, max, found } => {
+                write!(f, "expected {} to {} components, found {}", min, max, found)
+            }
+            ParseErrorReason::InvalidNumber => write!(f, "not a valid number"),
+            ParseErrorReason::OutOfRange { value, min, max } => {
+                write!(f, "{} is out of range {}..={}", value, min, max)
+            }
+            ParseErrorReason::BadHexLength(length) => {
+                write!(f, "hex colors need 3, 4, 6 or 8 digits, found {}", length)
+            }
+            ParseErrorReason::BadHexLiteralLength(length) => {
+                write!(f, "hex literals need 6 or 8 digits, found {}", length)
+            }
+            ParseErrorReason::InvalidHex => write!(f, "not a valid hex digit"),
+            ParseErrorReason::UnknownColorSpace => write!(f, "unknown color space"),
+            ParseErrorReason::UnknownName => write!(f, "unknown color name"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.format {
+            Some(format) => write!(f, "invalid {} color", format)?,
+            None => write!(f, "invalid color")?,
+        }
+        if let Some(component) = self.component {
+            write!(f, ", {} component", component)?;
+        }
+        write!(
+            f,
+            ": {} (bytes {}..{})",
+            self.reason, self.span.start, self.span.end
+        )
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,184 @@
+use std::{ops::Range, sync::LazyLock};
+
+use regex::Regex;
+
+use crate::color::{
+    CMYK, Color, ColorFormat, ColorMatch, HSL, HSV, HWB, LCh, Lab, LinearRgb, NamedColor, OkLab,
+    OkLch, ParseError, ParseErrorReason, RGB, RgbSpace, WideRgb, XYZ, YCbCr, YIQ, YUV,
+    parser::{color_space, split_function},
+};
+
+static HEX_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"#[a-fA-F0-9]+").unwrap());
+static LITERAL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b0[xX][a-zA-Z0-9]+\b").unwrap());
+static FUNCTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(rgba?|device-cmyk|cmyka?|hsla?|hsva?|hwb|lab(-d65)?|lch(-d65)?|oklab|oklch|color|ycbcr-bt(601|709|2020)(-full)?|yuv|yiq)\s*\(")
+        .unwrap()
+});
+static NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b[a-zA-Z]+\b").unwrap());
+
+/// Slice of an input that may hold a color, along with the kind of notation it looks like
+pub struct Candidate {
+    pub span: Range<usize>,
+    kind: String,
+}
+
+/// Every place of `input` that looks like a color, in order of precedence
+///
+/// Hex colors and `0x` literals come first, then functional notations, CSS color names and last
+/// bare hex digits, which are only taken when they are the whole input. Functional notation
+/// candidates run until the end of the input, their closing parenthesis is not known yet.
+pub fn find_candidates(input: &str) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = vec![];
+    for m in HEX_REGEX
+        .find_iter(input)
+        .chain(LITERAL_REGEX.find_iter(input))
+    {
+        candidates.push(Candidate {
+            span: m.range(),
+            kind: String::from("#"),
+        });
+    }
+    for captures in FUNCTION_REGEX.captures_iter(input) {
+        candidates.push(Candidate {
+            span: captures.get(0).unwrap().start()..input.len(),
+            kind: captures[1].to_lowercase(),
+        });
+    }
+    // Any word could be a name, so only the known ones are candidates, and not when they are
+    // part of an identifier such as `--brand-blue`, `text-red-500` or `$white`
+    for m in NAME_REGEX.find_iter(input) {
+        let before = input[..m.start()].chars().next_back();
+        let after = input[m.end()..].chars().next();
+        let in_identifier = matches!(before, Some('-' | '$' | '@')) || after == Some('-');
+        if !in_identifier && NamedColor::try_from(m.as_str()).is_ok() {
+            candidates.push(Candidate {
+                span: m.range(),
+                kind: String::from("named"),
+            });
+        }
+    }
+    let trimmed = input.trim();
+    if !trimmed.is_empty() && trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
+        let start = input.len() - input.trim_start().len();
+        candidates.push(Candidate {
+            span: start..start + trimmed.len(),
+            kind: String::from("#"),
+        });
+    }
+
+    candidates
+}
+
+/// Parses a candidate of `input`, returning the color found with its exact span
+pub fn parse_candidate(input: &str, candidate: &Candidate) -> Result<ColorMatch, ParseError> {
+    let text = &input[candidate.span.clone()];
+    let (color, format) = match candidate.kind.as_str() {
+        "rgb" | "rgba" => RGB::try_from(text).map(|color| (Color::from(color), ColorFormat::Rgb)),
+        "hsl" | "hsla" => HSL::try_from(text).map(|color| (Color::from(color), ColorFormat::Hsl)),
+        "hsv" | "hsva" => HSV::try_from(text).map(|color| (Color::from(color), ColorFormat::Hsv)),
+        "hwb" => HWB::try_from(text).map(|color| (Color::from(color), ColorFormat::Hwb)),
+        "cmyk" | "cmyka" | "device-cmyk" => {
+            CMYK::try_from(text).map(|color| (Color::from(color), ColorFormat::Cmyk))
+        }
+        "lab" | "lab-d65" => {
+            Lab::try_from(text).map(|color| (Color::from(color), ColorFormat::Lab))
+        }
+        "lch" | "lch-d65" => {
+            LCh::try_from(text).map(|color| (Color::from(color), ColorFormat::Lch))
+        }
+        "oklab" => OkLab::try_from(text).map(|color| (Color::from(color), ColorFormat::OkLab)),
+        "oklch" => OkLch::try_from(text).map(|color| (Color::from(color), ColorFormat::OkLch)),
+        "color" => parse_color_function(text),
+        "yuv" => YUV::try_from(text).map(|color| (Color::from(color), ColorFormat::Yuv)),
+        "yiq" => YIQ::try_from(text).map(|color| (Color::from(color), ColorFormat::Yiq)),
+        "named" => NamedColor::try_from(text).map(|color| (Color::from(color), ColorFormat::Named)),
+        ycbcr if ycbcr.starts_with("ycbcr") => {
+            YCbCr::try_from(text).map(|color| (Color::from(color), ColorFormat::YCbCr))
+        }
+        "#" if text.starts_with("0x") || text.starts_with("0X") => {
+            RGB::try_from(text).map(|color| (Color::from(color), ColorFormat::HexLiteral))
+        }
+        "#" => RGB::try_from(text).map(|color| (Color::from(color), ColorFormat::Hex)),
+        _ => Err(ParseError::new(
+            None,
+            0..text.len(),
+            ParseErrorReason::UnknownFormat,
+        )),
+    }
+    .map_err(|error| error.shifted(candidate.span.start))?;
+
+    // Functional notations end at their closing parenthesis, which parsing has just checked
+    let length = match candidate.kind.as_str() {
+        "#" | "named" => text.len(),
+        _ => text.find(')').map(|index| index + 1).unwrap_or(text.len()),
+    };
+    Ok(ColorMatch {
+        color,
+        format,
+        span: candidate.span.start..candidate.span.start + length,
+    })
+}
+
+/// Parses a `color()` function, dispatching on its color space
+fn parse_color_function(input: &str) -> Result<(Color, ColorFormat), ParseError> {
+    match color_space(input).as_deref() {
+        Some("xyz" | "xyz-d50" | "xyz-d65") => {
+            XYZ::try_from(input).map(|color| (Color::from(color), ColorFormat::Xyz))
+        }
+        Some("srgb-linear") => {
+            LinearRgb::try_from(input).map(|color| (Color::from(color), ColorFormat::LinearRgb))
+        }
+        Some(name) => match RgbSpace::from_name(name) {
+            Some(space) => WideRgb::try_from(input)
+                .map(|color| (Color::from(color), ColorFormat::WideRgb(space))),
+            None => Err(unknown_color_space(input)),
+        },
+        None => Err(unknown_color_space(input)),
+    }
+}
+
+/// Error of a `color()` function whose space is not known, or that is malformed
+fn unknown_color_space(input: &str) -> ParseError {
+    match split_function(input, ColorFormat::Color, &["color"], 4..=5) {
+        Ok(components) => ParseError::new(
+            Some(ColorFormat::Color),
+            components[0].span.clone(),
+            ParseErrorReason::UnknownColorSpace,
+        ),
+        Err(error) => error,
+    }
+}
+
+/// Whether a hex color is only the start of an identifier, as `#fab` in `#fab-button` or `#bad`
+/// in `#badge`
+fn runs_into_identifier(input: &str, found: &ColorMatch) -> bool {
+    let after = input[found.span.end..].chars().next();
+    matches!(found.format, ColorFormat::Hex | ColorFormat::HexLiteral)
+        && after.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+impl Color {
+    /// Finds every color in `input`, in the order they appear
+    ///
+    /// When two candidates overlap, the one that starts first is kept. Hex colors followed by a
+    /// word character or a `-` are part of an identifier and left out.
+    pub fn find_all(input: &str) -> Vec<ColorMatch> {
+        let mut matches: Vec<ColorMatch> = find_candidates(input)
+            .iter()
+            .filter_map(|candidate| parse_candidate(input, candidate).ok())
+            .filter(|found| !runs_into_identifier(input, found))
+            .collect();
+        matches.sort_by_key(|found| found.span.start);
+
+        let mut end = 0;
+        matches.retain(|found| {
+            let keep = found.span.start >= end;
+            if keep {
+                end = found.span.end;
+            }
+            keep
+        });
+        matches
+    }
+}
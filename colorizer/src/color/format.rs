@@ -0,0 +1,67 @@
+use std::fmt::Formatter;
+
+use crate::color::Alpha;
+
+/// Formats an alpha value with at most 3 decimals, enough to tell apart every 8 bit alpha
+pub fn format_alpha(alpha: Alpha) -> String {
+    format!("{}", (alpha * 1000.0).round() / 1000.0)
+}
+
+/// Rounds `value` to `precision` decimals, never writing a negative zero
+pub fn format_number(value: f64, precision: usize) -> String {
+    let text = format!("{:.*}", precision, value);
+    match text.strip_prefix('-') {
+        Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => unsigned.to_string(),
+        _ => text,
+    }
+}
+
+/// Rounds a hue to `precision` decimals, wrapping values that round up to a full turn
+pub fn format_hue(degrees: f64, precision: usize) -> String {
+    let factor = 10f64.powi(precision as i32);
+    format_number(
+        ((degrees * factor).round() / factor).rem_euclid(360.0),
+        precision,
+    )
+}
+
+/// Writes a functional notation such as `rgb(255, 0, 0)`
+///
+/// The legacy comma syntax is used by default, switching to `rgba()` style names when the color
+/// is translucent. The alternate flag (`{:#}`) writes the CSS Color 4 syntax instead, with the
+/// alpha after a slash: `rgb(255 0 0 / 0.5)`.
+pub fn write_function(
+    f: &mut Formatter<'_>,
+    name: &str,
+    channels: &[String],
+    alpha: Alpha,
+) -> std::fmt::Result {
+    if f.alternate() {
+        return write_modern_function(f, name, channels, alpha);
+    }
+
+    if alpha < 1.0 {
+        return write!(
+            f,
+            "{}a({}, {})",
+            name,
+            channels.join(", "),
+            format_alpha(alpha)
+        );
+    }
+    write!(f, "{}({})", name, channels.join(", "))
+}
+
+/// Writes a functional notation in the CSS Color 4 syntax: `lab(50% 40 -20 / 0.5)`
+pub fn write_modern_function(
+    f: &mut Formatter<'_>,
+    name: &str,
+    channels: &[String],
+    alpha: Alpha,
+) -> std::fmt::Result {
+    write!(f, "{}({}", name, channels.join(" "))?;
+    if alpha < 1.0 {
+        write!(f, " / {}", format_alpha(alpha))?;
+    }
+    write!(f, ")")
+}
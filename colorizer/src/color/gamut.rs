@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+use crate::color::{Color, Gamut, GamutMapping, OkLab, OkLch, WideRgb, XYZ, cie::Vector};
+
+/// Color difference under which a clipped color is considered indistinguishable from the
+/// original, in deltaE OK units
+const JUST_NOTICEABLE_DIFFERENCE: f64 = 0.02;
+/// Chroma resolution of the perceptual mapping search
+const CHROMA_RESOLUTION: f64 = 0.0001;
+/// Channel overflow still considered inside of a gamut, covering the rounding of the matrices
+/// between RGB spaces, far below a visible difference
+const GAMUT_TOLERANCE: f64 = 1e-5;
+
+impl Gamut {
+    /// Channels of `color` in the RGB space of the gamut, `0.0..=1.0` when inside of it
+    fn channels(&self, color: &Color) -> Vector {
+        match self {
+            Gamut::Srgb => [color.0, color.1, color.2],
+            Gamut::Wide(space) => {
+                let wide = WideRgb::from_xyz(XYZ::from(color.clone()), *space);
+                [wide.0, wide.1, wide.2]
+            }
+        }
+    }
+}
+
+impl Display for Gamut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Gamut::Srgb => write!(f, "sRGB"),
+            Gamut::Wide(space) => write!(f, "{}", space),
+        }
+    }
+}
+
+impl Color {
+    /// Whether `gamut` can represent the color without changing it
+    pub fn in_gamut(&self, gamut: Gamut) -> bool {
+        gamut
+            .channels(self)
+            .iter()
+            .all(|channel| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(channel))
+    }
+
+    /// Brings the color inside of `gamut`, colors already inside are returned unchanged
+    pub fn mapped_to_gamut(self, gamut: Gamut, mapping: GamutMapping) -> Color {
+        match mapping {
+            GamutMapping::Clip => self.clipped(gamut),
+            GamutMapping::Perceptual => self.reduced_chroma(gamut),
+        }
+    }
+
+    /// Clamps every channel of the color in the RGB space of `gamut`
+    fn clipped(self, gamut: Gamut) -> Color {
+        match gamut {
+            Gamut::Srgb => Color(
+                self.0.clamp(0.0, 1.0),
+                self.1.clamp(0.0, 1.0),
+                self.2.clamp(0.0, 1.0),
+                self.3,
+            ),
+            Gamut::Wide(space) => {
+                let wide = WideRgb::from_xyz(XYZ::from(self), space);
+                Color::from(WideRgb(
+                    wide.0.clamp(0.0, 1.0),
+                    wide.1.clamp(0.0, 1.0),
+                    wide.2.clamp(0.0, 1.0),
+                    wide.3,
+                    space,
+                ))
+            }
+        }
+    }
+
+    /// CSS Color 4 gamut mapping: lowers the OKLCH chroma, keeping lightness and hue, until
+    /// clipping what is left is not noticeable
+    fn reduced_chroma(self, gamut: Gamut) -> Color {
+        let origin = OkLch::from(self.clone());
+        if origin.0 >= 1.0 {
+            return Color(1.0, 1.0, 1.0, self.3);
+        }
+        if origin.0 <= 0.0 {
+            return Color(0.0, 0.0, 0.0, self.3);
+        }
+        if self.in_gamut(gamut) {
+            return self;
+        }
+
+        let difference =
+            |a: &Color, b: &Color| OkLab::from(a.clone()).delta_e(&OkLab::from(b.clone()));
+        let mut clipped = self.clone().clipped(gamut);
+        if difference(&clipped, &self) < JUST_NOTICEABLE_DIFFERENCE {
+            return clipped;
+        }
+
+        let (mut min, mut max) = (0.0, origin.1);
+        let mut min_in_gamut = true;
+        while max - min > CHROMA_RESOLUTION {
+            let chroma = (min + max) / 2.0;
+            let current = Color::from(OkLch(origin.0, chroma, origin.2, origin.3));
+            if min_in_gamut && current.in_gamut(gamut) {
+                min = chroma;
+                continue;
+            }
+
+            clipped = current.clone().clipped(gamut);
+            let error = difference(&clipped, &current);
+            if error < JUST_NOTICEABLE_DIFFERENCE {
+                if JUST_NOTICEABLE_DIFFERENCE - error < CHROMA_RESOLUTION {
+                    return clipped;
+                }
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+        clipped
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use crate::color::{Color, ColorHue, Harmony, OkLch};
+
+/// Lightest and darkest OKLCH lightness of a shade ramp
+const LIGHTEST_SHADE: f64 = 0.97;
+const DARKEST_SHADE: f64 = 0.25;
+
+impl Harmony {
+    pub const ALL: [Harmony; 5] = [
+        Harmony::Complementary,
+        Harmony::Analogous,
+        Harmony::Triadic,
+        Harmony::Tetradic,
+        Harmony::SplitComplementary,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Harmony::Complementary => "complementary",
+            Harmony::Analogous => "analogous",
+            Harmony::Triadic => "triadic",
+            Harmony::Tetradic => "tetradic",
+            Harmony::SplitComplementary => "split-complementary",
+        }
+    }
+
+    /// Finds a harmony by its name, ignoring case
+    pub fn from_name(name: &str) -> Option<Harmony> {
+        Self::ALL
+            .into_iter()
+            .find(|harmony| harmony.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Hue rotations of the colors of the scheme, the base color being the first one
+    fn rotations(&self) -> &'static [f64] {
+        match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::Analogous => &[0.0, -30.0, 30.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+            Harmony::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+            Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+        }
+    }
+}
+
+impl Display for Harmony {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Color {
+    /// Colors of `harmony` around this one, which comes first
+    ///
+    /// Hues are rotated in OKLCH, so every color keeps the perceived lightness and chroma of the
+    /// base color.
+    pub fn harmony(&self, harmony: Harmony) -> Vec<Color> {
+        let base = OkLch::from(self.clone());
+        harmony
+            .rotations()
+            .iter()
+            .map(|rotation| {
+                let hue = (base.2.to_f64() + rotation).rem_euclid(360.0);
+                Color::from(OkLch(base.0, base.1, ColorHue::new(hue), base.3))
+            })
+            .collect()
+    }
+
+    /// `count` shades of the color's hue and chroma, from lightest to darkest, with evenly spaced
+    /// OKLCH lightness
+    pub fn shades(&self, count: usize) -> Vec<Color> {
+        let base = OkLch::from(self.clone());
+        let step = match count {
+            0 | 1 => 0.0,
+            _ => (LIGHTEST_SHADE - DARKEST_SHADE) / (count - 1) as f64,
+        };
+        (0..count)
+            .map(|index| {
+                let lightness = LIGHTEST_SHADE - step * index as f64;
+                Color::from(OkLch(lightness, base.1, base.2, base.3))
+            })
+            .collect()
+    }
+}
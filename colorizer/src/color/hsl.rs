@@ -1,64 +1,90 @@
 use std::fmt::Display;
 
-use crate::color::{ColorHue, HSL, Percentage, RGB};
-use regex::Regex;
+use crate::color::{
+    Alpha, ColorFormat, ColorHue, HSL, ParseError, Percentage, RGB,
+    format::{format_hue, format_number, write_function},
+    parser::{parse_alpha, parse_channel, parse_hue, split_function},
+};
+use crate::core::ranged::EPSILON;
 
 impl HSL {
-    pub fn new(h: u16, s: u8, l: u8) -> Self {
+    pub fn new(h: impl Into<f64>, s: impl Into<f64>, l: impl Into<f64>) -> Self {
         Self(
-            ColorHue::new(h as i16),
-            Percentage::new(s as i16),
-            Percentage::new(l as i16),
+            ColorHue::new(h.into().rem_euclid(360.0)),
+            Percentage::new(s.into()),
+            Percentage::new(l.into()),
+            1.0,
         )
     }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
 }
 
 impl Display for HSL {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "hsl({}, {}%, {}%)", self.0, self.1, self.2)
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format_hue(self.0.to_f64(), precision),
+            format!("{}%", format_number(self.1.to_f64(), precision)),
+            format!("{}%", format_number(self.2.to_f64(), precision)),
+        ];
+        write_function(f, "hsl", &channels, self.3)
     }
 }
 
 impl PartialEq for HSL {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == other.1 && self.2 == other.2
+        self.0 == other.0
+            && self.1 == other.1
+            && self.2 == other.2
+            && (self.3 - other.3).abs() < EPSILON
     }
+}
+
+impl TryFrom<&str> for HSL {
+    type Error = ParseError;
+
+    /// Parses `hsl[a](h, s%, l%[, a])` or `hsl(h s% l%[ / a])`, the percent signs being optional
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_function(value.trim(), ColorFormat::Hsl, &["hsl", "hsla"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let h = parse_hue(&components[0], ColorFormat::Hsl);
+        let s = parse_channel(&components[1], ColorFormat::Hsl, "saturation", 100.0);
+        let l = parse_channel(&components[2], ColorFormat::Hsl, "lightness", 100.0);
 
-    fn ne(&self, other: &Self) -> bool {
-        self.0 != other.0 || self.1 != other.1 || self.2 != other.2
+        let alpha = parse_alpha(&components, 3, ColorFormat::Hsl);
+
+        match (h, s, l, alpha) {
+            (Ok(h), Ok(s), Ok(l), Ok(alpha)) => Ok(Self::new(h, s, l).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
     }
 }
 
-// TODO: manage error
-impl From<String> for HSL {
-    fn from(value: String) -> Self {
-        let regex = Regex::new(r"hsl\(([0-9]+),([0-9]+),([0-9 ]+)\)").unwrap();
-        let numbers = value.replace(" ", "").replace("%", "");
-        let result = regex.captures(&numbers);
-
-        match result {
-            Some(value_list) => {
-                // Numeric
-                let h: u16 = value_list[1].parse::<u16>().unwrap();
-                let s: u8 = value_list[2].parse::<u8>().unwrap();
-                let l: u8 = value_list[3].parse::<u8>().unwrap();
-                return Self::new(h, s, l);
-            }
-            None => (),
-        }
+impl TryFrom<String> for HSL {
+    type Error = ParseError;
 
-        Self::new(0, 0, 0)
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
     }
 }
 
 impl From<RGB> for HSL {
     fn from(value: RGB) -> Self {
-        let r = value.0.to_f32() / 255.0;
-        let g = value.1.to_f32() / 255.0;
-        let b = value.2.to_f32() / 255.0;
+        let r = value.0.to_f64() / 255.0;
+        let g = value.1.to_f64() / 255.0;
+        let b = value.2.to_f64() / 255.0;
 
-        let min: f32 = r.min(g.min(b));
-        let max: f32 = r.max(g.max(b));
+        let min: f64 = r.min(g.min(b));
+        let max: f64 = r.max(g.max(b));
 
         let h;
         let s;
@@ -87,10 +113,6 @@ impl From<RGB> for HSL {
             }
         }
 
-        HSL::new(
-            (h / 6.0 * 360.0).round() as u16,
-            (s * 100.0).round() as u8,
-            (l * 100.0).round() as u8,
-        )
+        HSL::new(h / 6.0 * 360.0, s * 100.0, l * 100.0).with_alpha(value.3)
     }
 }
@@ -1,69 +1,95 @@
-use regex::Regex;
 use std::fmt::Display;
 
-use crate::color::{ColorHue, HSV, Percentage, RGB};
+use crate::color::{
+    Alpha, ColorFormat, ColorHue, HSV, ParseError, Percentage, RGB,
+    format::{format_hue, format_number, write_function},
+    parser::{parse_alpha, parse_channel, parse_hue, split_function},
+};
+use crate::core::ranged::EPSILON;
 
 impl HSV {
-    pub fn new(h: u16, s: u8, v: u8) -> Self {
+    pub fn new(h: impl Into<f64>, s: impl Into<f64>, v: impl Into<f64>) -> Self {
         Self(
-            ColorHue::new(h as i16),
-            Percentage::new(s as i16),
-            Percentage::new(v as i16),
+            ColorHue::new(h.into().rem_euclid(360.0)),
+            Percentage::new(s.into()),
+            Percentage::new(v.into()),
+            1.0,
         )
     }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
 }
 
 impl Display for HSV {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "hsv({}, {}%, {}%)", self.0, self.1, self.2)
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format_hue(self.0.to_f64(), precision),
+            format!("{}%", format_number(self.1.to_f64(), precision)),
+            format!("{}%", format_number(self.2.to_f64(), precision)),
+        ];
+        write_function(f, "hsv", &channels, self.3)
     }
 }
 
 impl PartialEq for HSV {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == other.1 && self.2 == other.2
+        self.0 == other.0
+            && self.1 == other.1
+            && self.2 == other.2
+            && (self.3 - other.3).abs() < EPSILON
     }
+}
+
+impl TryFrom<&str> for HSV {
+    type Error = ParseError;
+
+    /// Parses `hsv[a](h, s%, v%[, a])` or `hsv(h s% v%[ / a])`, the percent signs being optional
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_function(value.trim(), ColorFormat::Hsv, &["hsv", "hsva"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
 
-    fn ne(&self, other: &Self) -> bool {
-        self.0 != other.0 || self.1 != other.1 || self.2 != other.2
+        let h = parse_hue(&components[0], ColorFormat::Hsv);
+        let s = parse_channel(&components[1], ColorFormat::Hsv, "saturation", 100.0);
+        let v = parse_channel(&components[2], ColorFormat::Hsv, "value", 100.0);
+
+        let alpha = parse_alpha(&components, 3, ColorFormat::Hsv);
+
+        match (h, s, v, alpha) {
+            (Ok(h), Ok(s), Ok(v), Ok(alpha)) => Ok(Self::new(h, s, v).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
     }
 }
 
-// TODO: manage error
-impl From<String> for HSV {
-    fn from(value: String) -> Self {
-        let regex = Regex::new(r"hsv\(([0-9]+),([0-9]+),([0-9 ]+)\)").unwrap();
-        let numbers = value.replace(" ", "").replace("%", "");
-        let result = regex.captures(&numbers);
-
-        match result {
-            Some(value_list) => {
-                // Numeric
-                let h: u16 = value_list[1].parse::<u16>().unwrap();
-                let s: u8 = value_list[2].parse::<u8>().unwrap();
-                let v: u8 = value_list[3].parse::<u8>().unwrap();
-                return Self::new(h, s, v);
-            }
-            None => (),
-        }
+impl TryFrom<String> for HSV {
+    type Error = ParseError;
 
-        Self::new(0, 0, 0)
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
     }
 }
 
 impl From<RGB> for HSV {
     fn from(color: RGB) -> Self {
-        let r = color.0.to_f32() / 255.0;
-        let g = color.1.to_f32() / 255.0;
-        let b = color.2.to_f32() / 255.0;
+        let r = color.0.to_f64() / 255.0;
+        let g = color.1.to_f64() / 255.0;
+        let b = color.2.to_f64() / 255.0;
 
-        let min: f32 = r.min(g.min(b));
-        let max: f32 = r.max(g.max(b));
+        let min: f64 = r.min(g.min(b));
+        let max: f64 = r.max(g.max(b));
 
         let v = max;
 
         if min == max {
-            return Self::new(0, 0, v as u8);
+            return Self::new(0, 0, v * 100.0).with_alpha(color.3);
         }
 
         let s = (max - min) / max;
@@ -72,7 +98,7 @@ impl From<RGB> for HSV {
         let gc = (max - g) / dif;
         let bc = (max - b) / dif;
 
-        let mut h: f32;
+        let mut h: f64;
         if r == max {
             h = bc - gc;
         } else if g == max {
@@ -83,10 +109,6 @@ impl From<RGB> for HSV {
 
         h = (h / 6.0).rem_euclid(1.0);
 
-        Self::new(
-            (h * 360.0).round() as u16,
-            (s * 100.0).round() as u8,
-            (v * 100.0).round() as u8,
-        )
+        Self::new(h * 360.0, s * 100.0, v * 100.0).with_alpha(color.3)
     }
 }
@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+use crate::color::{
+    Alpha, ColorFormat, ColorHue, HSV, HWB, ParseError, Percentage,
+    format::{format_hue, format_number, write_modern_function},
+    parser::{parse_alpha, parse_channel, parse_hue, split_function},
+};
+use crate::core::ranged::EPSILON;
+
+impl HWB {
+    pub fn new(h: impl Into<f64>, w: impl Into<f64>, b: impl Into<f64>) -> Self {
+        Self(
+            ColorHue::new(h.into().rem_euclid(360.0)),
+            Percentage::new(w.into()),
+            Percentage::new(b.into()),
+            1.0,
+        )
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Display for HWB {
+    /// Always uses the CSS Color 4 syntax, `hwb()` has no legacy form
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format_hue(self.0.to_f64(), precision),
+            format!("{}%", format_number(self.1.to_f64(), precision)),
+            format!("{}%", format_number(self.2.to_f64(), precision)),
+        ];
+        write_modern_function(f, "hwb", &channels, self.3)
+    }
+}
+
+impl PartialEq for HWB {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+            && self.1 == other.1
+            && self.2 == other.2
+            && (self.3 - other.3).abs() < EPSILON
+    }
+}
+
+impl TryFrom<&str> for HWB {
+    type Error = ParseError;
+
+    /// Parses `hwb(h w% b%[ / a])`, the percent signs being optional
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_function(value.trim(), ColorFormat::Hwb, &["hwb"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let h = parse_hue(&components[0], ColorFormat::Hwb);
+        let w = parse_channel(&components[1], ColorFormat::Hwb, "whiteness", 100.0);
+        let b = parse_channel(&components[2], ColorFormat::Hwb, "blackness", 100.0);
+
+        let alpha = parse_alpha(&components, 3, ColorFormat::Hwb);
+
+        match (h, w, b, alpha) {
+            (Ok(h), Ok(w), Ok(b), Ok(alpha)) => Ok(Self::new(h, w, b).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for HWB {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<HSV> for HWB {
+    fn from(color: HSV) -> Self {
+        let s = color.1.to_f64() / 100.0;
+        let v = color.2.to_f64() / 100.0;
+
+        Self::new(color.0.to_f64(), (1.0 - s) * v * 100.0, (1.0 - v) * 100.0).with_alpha(color.3)
+    }
+}
+
+impl From<HWB> for HSV {
+    /// Whiteness and blackness adding up to more than 100% are scaled down to a gray
+    fn from(color: HWB) -> Self {
+        let w = color.1.to_f64() / 100.0;
+        let b = color.2.to_f64() / 100.0;
+
+        if w + b >= 1.0 {
+            let gray = w / (w + b);
+            return Self::new(color.0.to_f64(), 0, gray * 100.0).with_alpha(color.3);
+        }
+
+        let v = 1.0 - b;
+        let s = 1.0 - w / v;
+        Self::new(color.0.to_f64(), s * 100.0, v * 100.0).with_alpha(color.3)
+    }
+}
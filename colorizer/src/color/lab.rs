@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, LCh, Lab, ParseError, Percentage, WhitePoint, XYZ,
+        cie::{lab_to_xyz, to_rectangular, xyz_to_lab},
+        format::{format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, parse_channel, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+impl Lab {
+    /// Creates a Lab color relative to D50, the white point CSS uses
+    pub fn new(l: impl Into<f64>, a: impl Into<f64>, b: impl Into<f64>) -> Self {
+        Self(
+            Percentage::new(l.into()),
+            a.into(),
+            b.into(),
+            1.0,
+            WhitePoint::D50,
+        )
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Expresses the same color relative to another white point, using Bradford adaptation
+    pub fn adapted_to(self, white: WhitePoint) -> Self {
+        Lab::from(XYZ::from(self).adapted_to(white))
+    }
+}
+
+impl Display for Lab {
+    /// Always uses the CSS Color 4 syntax, `lab()` has no legacy form
+    ///
+    /// CSS Lab is always relative to D50, so D65 values are written `lab-d65()`, a notation of
+    /// this crate that browsers do not read.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format!("{}%", format_number(self.0.to_f64(), precision)),
+            format_number(self.1, precision),
+            format_number(self.2, precision),
+        ];
+        let name = match self.4 {
+            WhitePoint::D50 => "lab",
+            WhitePoint::D65 => "lab-d65",
+        };
+        write_modern_function(f, name, &channels, self.3)
+    }
+}
+
+impl PartialEq for Lab {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+            && self.4 == other.4
+    }
+}
+
+impl TryFrom<&str> for Lab {
+    type Error = ParseError;
+
+    /// Parses `lab(L a b[ / alpha])`, or `lab-d65()` for D65 relative values
+    ///
+    /// As in CSS, `100%` is `100` for the lightness and `125` for the `a` and `b` axes.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let value = value.trim();
+        let white = match value.get(..7) {
+            Some(name) if name.eq_ignore_ascii_case("lab-d65") => WhitePoint::D65,
+            _ => WhitePoint::D50,
+        };
+        let components = split_function(value, ColorFormat::Lab, &["lab", "lab-d65"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let l = parse_channel(&components[0], ColorFormat::Lab, "lightness", 100.0);
+        let a = parse_axis(&components[1], ColorFormat::Lab, "a", 125.0);
+        let b = parse_axis(&components[2], ColorFormat::Lab, "b", 125.0);
+        let alpha = parse_alpha(&components, 3, ColorFormat::Lab);
+
+        match (l, a, b, alpha) {
+            (Ok(l), Ok(a), Ok(b), Ok(alpha)) => Ok(Self(Percentage::new(l), a, b, alpha, white)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for Lab {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<XYZ> for Lab {
+    /// Keeps the white point of the XYZ values
+    fn from(color: XYZ) -> Self {
+        let lab = xyz_to_lab([color.0, color.1, color.2], color.4);
+        let relative = Self::new(lab[0], lab[1], lab[2]).with_alpha(color.3);
+        Self(relative.0, relative.1, relative.2, relative.3, color.4)
+    }
+}
+
+impl From<Lab> for XYZ {
+    fn from(color: Lab) -> Self {
+        let xyz = lab_to_xyz([color.0.to_f64(), color.1, color.2], color.4);
+        XYZ(xyz[0], xyz[1], xyz[2], color.3, color.4)
+    }
+}
+
+impl From<Color> for Lab {
+    fn from(color: Color) -> Self {
+        Lab::from(XYZ::from(color).adapted_to(WhitePoint::D50))
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(color: Lab) -> Self {
+        Color::from(XYZ::from(color))
+    }
+}
+
+impl From<LCh> for Lab {
+    fn from(color: LCh) -> Self {
+        let lab = to_rectangular([color.0.to_f64(), color.1, color.2.to_f64()]);
+        Self(Percentage::new(lab[0]), lab[1], lab[2], color.3, color.4)
+    }
+}
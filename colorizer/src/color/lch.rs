@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, ColorHue, LCh, Lab, ParseError, Percentage, WhitePoint,
+        cie::to_polar,
+        format::{format_hue, format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, parse_channel, parse_hue, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+impl LCh {
+    /// Creates a LCh color relative to D50, the white point CSS uses
+    pub fn new(l: impl Into<f64>, c: impl Into<f64>, h: impl Into<f64>) -> Self {
+        Self(
+            Percentage::new(l.into()),
+            c.into().max(0.0),
+            ColorHue::new(h.into().rem_euclid(360.0)),
+            1.0,
+            WhitePoint::D50,
+        )
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Expresses the same color relative to another white point, using Bradford adaptation
+    pub fn adapted_to(self, white: WhitePoint) -> Self {
+        LCh::from(Lab::from(self).adapted_to(white))
+    }
+}
+
+impl Display for LCh {
+    /// Always uses the CSS Color 4 syntax, `lch()` has no legacy form
+    ///
+    /// CSS LCh is always relative to D50, so D65 values are written `lch-d65()`, a notation of
+    /// this crate that browsers do not read.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format!("{}%", format_number(self.0.to_f64(), precision)),
+            format_number(self.1, precision),
+            format_hue(self.2.to_f64(), precision),
+        ];
+        let name = match self.4 {
+            WhitePoint::D50 => "lch",
+            WhitePoint::D65 => "lch-d65",
+        };
+        write_modern_function(f, name, &channels, self.3)
+    }
+}
+
+impl PartialEq for LCh {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+            && (self.1 - other.1).abs() < EPSILON
+            && self.2 == other.2
+            && (self.3 - other.3).abs() < EPSILON
+            && self.4 == other.4
+    }
+}
+
+impl TryFrom<&str> for LCh {
+    type Error = ParseError;
+
+    /// Parses `lch(L C h[ / alpha])`, or `lch-d65()` for D65 relative values
+    ///
+    /// As in CSS, `100%` is `100` for the lightness and `150` for the chroma. Negative chromas
+    /// are clamped to `0`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let value = value.trim();
+        let white = match value.get(..7) {
+            Some(name) if name.eq_ignore_ascii_case("lch-d65") => WhitePoint::D65,
+            _ => WhitePoint::D50,
+        };
+        let components = split_function(value, ColorFormat::Lch, &["lch", "lch-d65"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let l = parse_channel(&components[0], ColorFormat::Lch, "lightness", 100.0);
+        let c = parse_axis(&components[1], ColorFormat::Lch, "chroma", 150.0);
+        let h = parse_hue(&components[2], ColorFormat::Lch);
+        let alpha = parse_alpha(&components, 3, ColorFormat::Lch);
+
+        match (l, c, h, alpha) {
+            (Ok(l), Ok(c), Ok(h), Ok(alpha)) => {
+                let color = Self::new(l, c, h).with_alpha(alpha);
+                Ok(Self(color.0, color.1, color.2, color.3, white))
+            }
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for LCh {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Lab> for LCh {
+    fn from(color: Lab) -> Self {
+        let lch = to_polar([color.0.to_f64(), color.1, color.2]);
+        let polar = Self::new(lch[0], lch[1], lch[2]).with_alpha(color.3);
+        Self(polar.0, polar.1, polar.2, polar.3, color.4)
+    }
+}
+
+impl From<Color> for LCh {
+    fn from(color: Color) -> Self {
+        LCh::from(Lab::from(color))
+    }
+}
+
+impl From<LCh> for Color {
+    fn from(color: LCh) -> Self {
+        Color::from(Lab::from(color))
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, LinearRgb, ParseError, WhitePoint, XYZ,
+        cie::{Matrix, adapt, linear_to_srgb, multiply, srgb_to_linear},
+        format::{format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, split_color_function},
+    },
+    core::ranged::EPSILON,
+};
+
+/// Linear sRGB to CIE XYZ relative to D65, as defined by CSS Color 4
+const LINEAR_SRGB_TO_XYZ_D65: Matrix = [
+    [0.4123907992659595, 0.35758433938387796, 0.1804807884018343],
+    [0.21263900587151036, 0.7151686787677559, 0.07219231536073371],
+    [0.01933081871559185, 0.11919477979462599, 0.9505321522496606],
+];
+const XYZ_D65_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045213, -1.5373831775700935, -0.4986107602930033],
+    [-0.9692436362808798, 1.8759675015077206, 0.04155505740717561],
+    [
+        0.05563007969699361,
+        -0.20397695888897657,
+        1.0569715142428786,
+    ],
+];
+
+impl LinearRgb {
+    pub fn new(r: impl Into<f64>, g: impl Into<f64>, b: impl Into<f64>) -> Self {
+        Self(r.into(), g.into(), b.into(), 1.0)
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Display for LinearRgb {
+    /// Always uses the CSS Color 4 `color(srgb-linear r g b)` syntax
+    ///
+    /// Channels go from `0` to `1`, so they get 3 more decimals than the requested precision.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            "srgb-linear".to_string(),
+            format_number(self.0, precision + 3),
+            format_number(self.1, precision + 3),
+            format_number(self.2, precision + 3),
+        ];
+        write_modern_function(f, "color", &channels, self.3)
+    }
+}
+
+impl PartialEq for LinearRgb {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+    }
+}
+
+impl TryFrom<&str> for LinearRgb {
+    type Error = ParseError;
+
+    /// Parses `color(srgb-linear r g b[ / alpha])`, where `100%` is `1`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components =
+            split_color_function(value.trim(), ColorFormat::LinearRgb, &["srgb-linear"])
+                .map_err(|error| error.shifted(offset))?;
+
+        let r = parse_axis(&components[1], ColorFormat::LinearRgb, "red", 1.0);
+        let g = parse_axis(&components[2], ColorFormat::LinearRgb, "green", 1.0);
+        let b = parse_axis(&components[3], ColorFormat::LinearRgb, "blue", 1.0);
+        let alpha = parse_alpha(&components, 4, ColorFormat::LinearRgb);
+
+        match (r, g, b, alpha) {
+            (Ok(r), Ok(g), Ok(b), Ok(alpha)) => Ok(Self::new(r, g, b).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for LinearRgb {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Color> for LinearRgb {
+    fn from(color: Color) -> Self {
+        Self(
+            srgb_to_linear(color.0),
+            srgb_to_linear(color.1),
+            srgb_to_linear(color.2),
+            color.3,
+        )
+    }
+}
+
+impl From<LinearRgb> for Color {
+    fn from(color: LinearRgb) -> Self {
+        Color(
+            linear_to_srgb(color.0),
+            linear_to_srgb(color.1),
+            linear_to_srgb(color.2),
+            color.3,
+        )
+    }
+}
+
+impl From<XYZ> for LinearRgb {
+    fn from(color: XYZ) -> Self {
+        let xyz = adapt([color.0, color.1, color.2], color.4, WhitePoint::D65);
+        let rgb = multiply(&XYZ_D65_TO_LINEAR_SRGB, xyz);
+        Self(rgb[0], rgb[1], rgb[2], color.3)
+    }
+}
+
+impl From<LinearRgb> for XYZ {
+    fn from(color: LinearRgb) -> Self {
+        let xyz = multiply(&LINEAR_SRGB_TO_XYZ_D65, [color.0, color.1, color.2]);
+        XYZ::new(xyz[0], xyz[1], xyz[2]).with_alpha(color.3)
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use crate::color::{Color, ColorHue, Lab, LinearRgb, MixSpace, OkLab, OkLch, cie::Vector};
+
+/// Chroma under which the hue of an OKLCH color is meaningless and the other hue is used
+const ACHROMATIC_CHROMA: f64 = 1e-4;
+
+impl MixSpace {
+    pub const ALL: [MixSpace; 5] = [
+        MixSpace::Srgb,
+        MixSpace::LinearRgb,
+        MixSpace::Lab,
+        MixSpace::OkLab,
+        MixSpace::OkLch,
+    ];
+
+    /// Identifier of the space inside CSS `color-mix()`
+    pub fn name(&self) -> &'static str {
+        match self {
+            MixSpace::Srgb => "srgb",
+            MixSpace::LinearRgb => "srgb-linear",
+            MixSpace::Lab => "lab",
+            MixSpace::OkLab => "oklab",
+            MixSpace::OkLch => "oklch",
+        }
+    }
+
+    /// Finds a space by its CSS identifier, ignoring case
+    pub fn from_name(name: &str) -> Option<MixSpace> {
+        Self::ALL
+            .into_iter()
+            .find(|space| space.name().eq_ignore_ascii_case(name))
+    }
+
+    fn coordinates(&self, color: &Color) -> Vector {
+        match self {
+            MixSpace::Srgb => [color.0, color.1, color.2],
+            MixSpace::LinearRgb => {
+                let linear = LinearRgb::from(color.clone());
+                [linear.0, linear.1, linear.2]
+            }
+            MixSpace::Lab => {
+                let lab = Lab::from(color.clone());
+                [lab.0.to_f64(), lab.1, lab.2]
+            }
+            MixSpace::OkLab => {
+                let oklab = OkLab::from(color.clone());
+                [oklab.0, oklab.1, oklab.2]
+            }
+            MixSpace::OkLch => {
+                let oklch = OkLch::from(color.clone());
+                [oklch.0, oklch.1, oklch.2.to_f64()]
+            }
+        }
+    }
+
+    fn color(&self, coordinates: Vector, alpha: f64) -> Color {
+        let [a, b, c] = coordinates;
+        match self {
+            MixSpace::Srgb => Color(a, b, c, alpha),
+            MixSpace::LinearRgb => Color::from(LinearRgb(a, b, c, alpha)),
+            MixSpace::Lab => Color::from(Lab::new(a, b, c).with_alpha(alpha)),
+            MixSpace::OkLab => Color::from(OkLab(a, b, c, alpha)),
+            MixSpace::OkLch => Color::from(OkLch(a, b, ColorHue::new(c), alpha)),
+        }
+    }
+}
+
+impl Display for MixSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Color {
+    /// Mixes `amount`, from `0.0` to `1.0`, of `other` into the color, interpolating in `space`
+    ///
+    /// As in CSS `color-mix()`, channels are premultiplied by alpha so transparent colors don't
+    /// tint the result, and OKLCH hues go along the shorter arc.
+    pub fn mix(&self, other: &Color, amount: f64, space: MixSpace) -> Color {
+        let amount = amount.clamp(0.0, 1.0);
+        let mut from = space.coordinates(self);
+        let mut to = space.coordinates(other);
+        let alpha = self.3 + (other.3 - self.3) * amount;
+
+        if space == MixSpace::OkLch {
+            if from[1] < ACHROMATIC_CHROMA {
+                from[2] = to[2];
+            } else if to[1] < ACHROMATIC_CHROMA {
+                to[2] = from[2];
+            }
+            let arc = (to[2] - from[2] + 180.0).rem_euclid(360.0) - 180.0;
+            to[2] = from[2] + arc;
+        }
+        // The hue is the only channel that is not premultiplied
+        let premultiplied = |index: usize| space != MixSpace::OkLch || index < 2;
+
+        let mut mixed = [0.0; 3];
+        for index in 0..3 {
+            mixed[index] = match premultiplied(index) {
+                true => {
+                    let a = from[index] * self.3;
+                    let b = to[index] * other.3;
+                    match alpha > 0.0 {
+                        true => (a + (b - a) * amount) / alpha,
+                        false => 0.0,
+                    }
+                }
+                false => from[index] + (to[index] - from[index]) * amount,
+            };
+        }
+        if space == MixSpace::OkLch {
+            mixed[2] = mixed[2].rem_euclid(360.0);
+        }
+        space.color(mixed, alpha)
+    }
+}
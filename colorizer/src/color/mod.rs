@@ -2,120 +2,331 @@
 #[path = "./test/colors.test.rs"]
 mod test;
 
+mod cie;
+pub mod cmyk;
+pub mod contrast;
+pub mod error;
+mod extract;
+mod format;
+pub mod gamut;
+pub mod harmony;
 pub mod hsl;
 pub mod hsv;
+pub mod hwb;
+pub mod lab;
+pub mod lch;
+pub mod linear_rgb;
+pub mod mix;
+pub mod named;
+pub mod oklab;
+pub mod oklch;
+mod parser;
 pub mod rgb;
+pub mod target_format;
+pub mod wide_rgb;
+pub mod xyz;
+pub mod ycbcr;
+pub mod yiq;
+pub mod yuv;
 
-use std::fmt::UpperHex;
+use std::{fmt::UpperHex, ops::Range};
 
-use regex::Regex;
+use crate::core::ranged::{EPSILON, RangedFloat};
 
-use crate::core::ranged::RangedInt;
+pub use error::{ColorFormat, ParseError, ParseErrorReason};
+use extract::{find_candidates, parse_candidate};
 
-pub type ColorIntensity = RangedInt<0, 255>;
-pub type ColorHue = RangedInt<0, 360>;
-pub type Percentage = RangedInt<0, 100>;
+pub type ColorIntensity = RangedFloat<0, 255>;
+pub type ColorHue = RangedFloat<0, 360>;
+pub type Percentage = RangedFloat<0, 100>;
+/// Opacity of a color, from `0.0` (transparent) to `1.0` (opaque)
+pub type Alpha = f64;
 #[derive(Debug, Clone)]
-pub struct RGB(pub ColorIntensity, pub ColorIntensity, pub ColorIntensity);
+pub struct RGB(
+    pub ColorIntensity,
+    pub ColorIntensity,
+    pub ColorIntensity,
+    pub Alpha,
+);
 #[derive(Debug, Clone)]
-pub struct HSL(ColorHue, Percentage, Percentage);
+pub struct HSL(pub ColorHue, pub Percentage, pub Percentage, pub Alpha);
 #[derive(Debug, Clone)]
-pub struct HSV(ColorHue, Percentage, Percentage);
+pub struct HSV(pub ColorHue, pub Percentage, pub Percentage, pub Alpha);
+/// Hue, whiteness, blackness
 #[derive(Debug, Clone)]
-pub struct Color(RGB);
+pub struct HWB(pub ColorHue, pub Percentage, pub Percentage, pub Alpha);
+/// Reference white the CIE based models are relative to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitePoint {
+    D50,
+    D65,
+}
+/// sRGB without its transfer function, channels nominally going from `0.0` to `1.0`
+#[derive(Debug, Clone)]
+pub struct LinearRgb(pub f64, pub f64, pub f64, pub Alpha);
+/// CIE XYZ tristimulus values, `Y` going from `0.0` to `1.0` for the reference white
+#[derive(Debug, Clone)]
+pub struct XYZ(pub f64, pub f64, pub f64, pub Alpha, pub WhitePoint);
+/// RGB color spaces wider than sRGB, readable through CSS `color()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RgbSpace {
+    DisplayP3,
+    A98Rgb,
+    ProPhotoRgb,
+    Rec2020,
+}
+/// Gamma encoded RGB in one of the wide gamut spaces, channels going from `0.0` to `1.0`
+#[derive(Debug, Clone)]
+pub struct WideRgb(pub f64, pub f64, pub f64, pub Alpha, pub RgbSpace);
+/// Cyan, magenta, yellow and black ink coverage
+#[derive(Debug, Clone)]
+pub struct CMYK(
+    pub Percentage,
+    pub Percentage,
+    pub Percentage,
+    pub Percentage,
+    pub Alpha,
+);
+/// How much of the gray shared by the cyan, magenta and yellow inks is printed with black instead
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlackGeneration {
+    /// All of it, black being `1 - max(r, g, b)` as in CSS `device-cmyk()`
+    Naive,
+    /// Under color removal: only grays darker than `start`, from `0.0` to `1.0`, get black, more
+    /// of it the darker they are
+    UnderColorRemoval { start: f64 },
+    /// Gray component replacement: `amount`, from `0.0` to `1.0`, of the gray of every color
+    GrayComponentReplacement { amount: f64 },
+}
+/// Luma coefficients of the digital video standards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LumaStandard {
+    Bt601,
+    Bt709,
+    Bt2020,
+}
+/// Code values a video signal uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalRange {
+    /// Every 8 bit value, as in JPEG
+    Full,
+    /// Studio range: luma from `16` to `235`, chroma from `16` to `240`
+    Limited,
+}
+/// Digital video luma and chroma differences: `Y'` from `0.0` to `1.0`, `Cb` and `Cr` from
+/// `-0.5` to `0.5`
+#[derive(Debug, Clone)]
+pub struct YCbCr(
+    pub f64,
+    pub f64,
+    pub f64,
+    pub Alpha,
+    pub LumaStandard,
+    pub SignalRange,
+);
+/// Analog PAL luma and scaled chroma differences
+#[derive(Debug, Clone)]
+pub struct YUV(pub f64, pub f64, pub f64, pub Alpha);
+/// Analog NTSC luma, in-phase and quadrature chroma
+#[derive(Debug, Clone)]
+pub struct YIQ(pub f64, pub f64, pub f64, pub Alpha);
+/// CSS named color, such as `tomato` or `transparent`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedColor(&'static str);
+/// Range of colors an output can represent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gamut {
+    Srgb,
+    Wide(RgbSpace),
+}
+/// How colors outside of a gamut are brought inside of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamutMapping {
+    /// Clamps each channel, fast but it can shift the hue and lightness
+    Clip,
+    /// Reduces the OKLCH chroma until the color fits, as CSS Color 4 does
+    Perceptual,
+}
+/// CIE Lab: lightness, green-red axis, blue-yellow axis
+#[derive(Debug, Clone)]
+pub struct Lab(pub Percentage, pub f64, pub f64, pub Alpha, pub WhitePoint);
+/// Cylindrical CIE Lab: lightness, chroma, hue
+#[derive(Debug, Clone)]
+pub struct LCh(
+    pub Percentage,
+    pub f64,
+    pub ColorHue,
+    pub Alpha,
+    pub WhitePoint,
+);
+/// OKLab: perceptual lightness from `0.0` to `1.0`, green-red axis, blue-yellow axis
+#[derive(Debug, Clone)]
+pub struct OkLab(pub f64, pub f64, pub f64, pub Alpha);
+/// Cylindrical OKLab: lightness, chroma, hue
+#[derive(Debug, Clone)]
+pub struct OkLch(pub f64, pub f64, pub ColorHue, pub Alpha);
+/// Space colors are interpolated in when mixed, as in CSS `color-mix()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MixSpace {
+    Srgb,
+    LinearRgb,
+    Lab,
+    OkLab,
+    /// Interpolates the hue along the shorter arc
+    OkLch,
+}
+/// Color schemes built by rotating the OKLCH hue of a base color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Harmony {
+    Complementary,
+    Analogous,
+    Triadic,
+    Tetradic,
+    SplitComplementary,
+}
+/// Notation colors are converted into when no one is there to choose it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetFormat {
+    /// Exact CSS name of the color, or the nearest one
+    Named,
+    Hex,
+    /// `#RGB` or `#RGBA` when every channel repeats its digit
+    ShortHex,
+    Rgb,
+    /// CSS Color 4 space separated syntax
+    ModernRgb,
+    Hsl,
+    /// CSS Color 4 space separated syntax
+    ModernHsl,
+    Hsv,
+    Hwb,
+    Cmyk,
+    /// CSS `device-cmyk()`
+    DeviceCmyk,
+    Lab(WhitePoint),
+    Lch(WhitePoint),
+    OkLab,
+    OkLch,
+    LinearRgb,
+    Xyz(WhitePoint),
+    YCbCr(LumaStandard, SignalRange),
+    Yuv,
+    Yiq,
+    Wide(RgbSpace),
+}
+/// Color found inside of a bigger text, `span` being its byte range in that text
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorMatch {
+    pub color: Color,
+    pub format: ColorFormat,
+    pub span: Range<usize>,
+}
+/// Full precision sRGB color, channels going from `0.0` to `1.0`
+///
+/// Every model converts through it. Values are never rounded here, only when displayed, and
+/// they may fall outside of `0.0..=1.0` for colors out of the sRGB gamut. Perceptual and wide
+/// gamut spaces get here through `LinearRgb` and `XYZ`.
+#[derive(Debug, Clone)]
+pub struct Color(pub f64, pub f64, pub f64, pub Alpha);
 
 impl Color {
-    pub fn try_parse(input: String) -> Result<Color, ()> {
-        let input = input.replace(" ", "").to_lowercase();
-
-        // TODO: clean all of this to manage errors on a clean and simple way
-        // - Move down to custom trait that returns a Result
-        let hex_regex = Regex::new(r".*(#[a-fA-F0-9]{3,6}).*").unwrap();
-        let rgb_regex =
-            Regex::new(r".*(rgb\([ ]*[0-9]+[ ]*,[ ]*[0-9]+[ ]*,[ ]*[0-9 ]+[ ]*\)).*").unwrap();
-        let hsl_regex =
-            Regex::new(r".*(hsl\([ ]*[0-9]+[ ]*,[0-9]+[ ]*[%]*[ ]*,[0-9 ]+[ ]*[%]*[ ]*\)).*")
-                .unwrap();
-        let hsv_regex =
-            Regex::new(r".*(hsv\([ ]*[0-9]+[ ]*,[0-9]+[ ]*[%]*[ ]*,[0-9 ]+[ ]*[%]*[ ]*\)).*")
-                .unwrap();
-
-        let hex_result = hex_regex.captures(&input);
-        match hex_result {
-            Some(color) => return Ok(Color::from(RGB::from(color[1].to_string()))),
-            None => (),
-        }
-
-        let rgb_result = rgb_regex.captures(&input);
-        match rgb_result {
-            Some(color) => return Ok(Color::from(RGB::from(color[1].to_string()))),
-            None => (),
-        }
-
-        let hsl_result = hsl_regex.captures(&input);
-        match hsl_result {
-            Some(color) => return Ok(Color::from(HSL::from(color[1].to_string()))),
-            None => (),
+    /// Finds and parses the first color in `input`
+    ///
+    /// Hex colors and `0x` literals take precedence over functional notations, then come CSS
+    /// color names and last bare hex digits. When every candidate fails, the error of the first
+    /// one is returned. Use `Color::find_all` to get every color instead.
+    pub fn try_parse(input: String) -> Result<Color, ParseError> {
+        let mut first_error: Option<ParseError> = None;
+        for candidate in find_candidates(&input) {
+            match parse_candidate(&input, &candidate) {
+                Ok(found) => return Ok(found.color),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
         }
 
-        let hsv_result = hsv_regex.captures(&input);
-        match hsv_result {
-            Some(color) => return Ok(Color::from(HSV::from(color[1].to_string()))),
-            None => (),
-        }
+        Err(first_error.unwrap_or(ParseError::new(
+            None,
+            0..input.len(),
+            ParseErrorReason::UnknownFormat,
+        )))
+    }
 
-        Err(())
+    pub fn alpha(&self) -> Alpha {
+        self.3
     }
 }
 
 impl UpperHex for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:X}", self.0)
+        match f.alternate() {
+            true => write!(f, "{:#X}", RGB::from(self.clone())),
+            false => write!(f, "{:X}", RGB::from(self.clone())),
+        }
     }
 }
 
 impl PartialEq for Color {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
-    }
-
-    fn ne(&self, other: &Self) -> bool {
-        self.0 != other.0
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
     }
 }
 
 impl From<RGB> for Color {
     fn from(color: RGB) -> Self {
-        Self(color)
+        Self(
+            color.0.to_f64() / 255.0,
+            color.1.to_f64() / 255.0,
+            color.2.to_f64() / 255.0,
+            color.3,
+        )
     }
 }
 
 impl From<HSL> for Color {
     fn from(color: HSL) -> Self {
-        Color(RGB::from(color))
+        Color::from(RGB::from(color))
     }
 }
 
 impl From<HSV> for Color {
     fn from(color: HSV) -> Self {
-        Color(RGB::from(color))
+        Color::from(RGB::from(color))
+    }
+}
+
+impl From<Color> for RGB {
+    /// Maps colors outside of sRGB perceptually instead of clamping each channel
+    fn from(color: Color) -> Self {
+        let color = color.mapped_to_gamut(Gamut::Srgb, GamutMapping::Perceptual);
+        RGB::new(color.0 * 255.0, color.1 * 255.0, color.2 * 255.0).with_alpha(color.3)
+    }
+}
+
+impl From<Color> for HSL {
+    fn from(color: Color) -> Self {
+        HSL::from(RGB::from(color))
     }
 }
 
-impl Into<RGB> for Color {
-    fn into(self) -> RGB {
-        self.0
+impl From<Color> for HSV {
+    fn from(color: Color) -> Self {
+        HSV::from(RGB::from(color))
     }
 }
 
-impl Into<HSL> for Color {
-    fn into(self) -> HSL {
-        HSL::from(self.0)
+impl From<HWB> for Color {
+    fn from(color: HWB) -> Self {
+        Color::from(HSV::from(color))
     }
 }
 
-impl Into<HSV> for Color {
-    fn into(self) -> HSV {
-        HSV::from(self.0)
+impl From<Color> for HWB {
+    fn from(color: Color) -> Self {
+        HWB::from(HSV::from(color))
     }
 }
@@ -0,0 +1,158 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, LinearRgb, OkLab, OkLch, ParseError,
+        cie::{Matrix, multiply, to_rectangular},
+        format::{format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, parse_channel, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+/// Linear sRGB to LMS cone responses, from Björn Ottosson's OKLab definition
+const LINEAR_SRGB_TO_LMS: Matrix = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+const LMS_TO_LINEAR_SRGB: Matrix = [
+    [4.076741661347994, -3.3077115904081933, 0.2309699287294279],
+    [-1.268438004092176, 2.6097574006633715, -0.3413193963102196],
+    [
+        -0.004196086541837109,
+        -0.7034186144594496,
+        1.7076147009309448,
+    ],
+];
+
+/// Non linear LMS to OKLab
+const LMS_TO_OKLAB: Matrix = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+const OKLAB_TO_LMS: Matrix = [
+    [0.9999999984505198, 0.39633779217376786, 0.2158037580607588],
+    [
+        1.0000000088817609,
+        -0.10556134232365635,
+        -0.06385417477170591,
+    ],
+    [
+        1.0000000546724108,
+        -0.08948418209496575,
+        -1.2914855378640917,
+    ],
+];
+
+impl OkLab {
+    pub fn new(l: impl Into<f64>, a: impl Into<f64>, b: impl Into<f64>) -> Self {
+        Self(l.into().clamp(0.0, 1.0), a.into(), b.into(), 1.0)
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Euclidean distance to `other`, the deltaE OK color difference
+    pub fn delta_e(&self, other: &OkLab) -> f64 {
+        ((self.0 - other.0).powi(2) + (self.1 - other.1).powi(2) + (self.2 - other.2).powi(2))
+            .sqrt()
+    }
+}
+
+impl Display for OkLab {
+    /// Always uses the CSS Color 4 syntax, with the lightness as a percentage
+    ///
+    /// The `a` and `b` axes stay within about `±0.4`, so they get 3 more decimals than the
+    /// requested precision.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format!("{}%", format_number(self.0 * 100.0, precision)),
+            format_number(self.1, precision + 3),
+            format_number(self.2, precision + 3),
+        ];
+        write_modern_function(f, "oklab", &channels, self.3)
+    }
+}
+
+impl PartialEq for OkLab {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+    }
+}
+
+impl TryFrom<&str> for OkLab {
+    type Error = ParseError;
+
+    /// Parses `oklab(L a b[ / alpha])`
+    ///
+    /// As in CSS, `100%` is `1` for the lightness and `0.4` for the `a` and `b` axes.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_function(value.trim(), ColorFormat::OkLab, &["oklab"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let l = parse_channel(&components[0], ColorFormat::OkLab, "lightness", 1.0);
+        let a = parse_axis(&components[1], ColorFormat::OkLab, "a", 0.4);
+        let b = parse_axis(&components[2], ColorFormat::OkLab, "b", 0.4);
+        let alpha = parse_alpha(&components, 3, ColorFormat::OkLab);
+
+        match (l, a, b, alpha) {
+            (Ok(l), Ok(a), Ok(b), Ok(alpha)) => Ok(Self::new(l, a, b).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for OkLab {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<LinearRgb> for OkLab {
+    fn from(color: LinearRgb) -> Self {
+        let lms = multiply(&LINEAR_SRGB_TO_LMS, [color.0, color.1, color.2]);
+        let lab = multiply(&LMS_TO_OKLAB, lms.map(f64::cbrt));
+        Self::new(lab[0], lab[1], lab[2]).with_alpha(color.3)
+    }
+}
+
+impl From<OkLab> for LinearRgb {
+    fn from(color: OkLab) -> Self {
+        let lms = multiply(&OKLAB_TO_LMS, [color.0, color.1, color.2]).map(|value| value.powi(3));
+        let rgb = multiply(&LMS_TO_LINEAR_SRGB, lms);
+        LinearRgb::new(rgb[0], rgb[1], rgb[2]).with_alpha(color.3)
+    }
+}
+
+impl From<Color> for OkLab {
+    fn from(color: Color) -> Self {
+        OkLab::from(LinearRgb::from(color))
+    }
+}
+
+impl From<OkLab> for Color {
+    fn from(color: OkLab) -> Self {
+        Color::from(LinearRgb::from(color))
+    }
+}
+
+impl From<OkLch> for OkLab {
+    fn from(color: OkLch) -> Self {
+        let lab = to_rectangular([color.0, color.1, color.2.to_f64()]);
+        Self::new(lab[0], lab[1], lab[2]).with_alpha(color.3)
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, ColorHue, OkLab, OkLch, ParseError,
+        cie::to_polar,
+        format::{format_hue, format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, parse_channel, parse_hue, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+impl OkLch {
+    pub fn new(l: impl Into<f64>, c: impl Into<f64>, h: impl Into<f64>) -> Self {
+        Self(
+            l.into().clamp(0.0, 1.0),
+            c.into().max(0.0),
+            ColorHue::new(h.into().rem_euclid(360.0)),
+            1.0,
+        )
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Display for OkLch {
+    /// Always uses the CSS Color 4 syntax, with the lightness as a percentage
+    ///
+    /// The chroma stays under about `0.4`, so it gets 3 more decimals than the requested
+    /// precision.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format!("{}%", format_number(self.0 * 100.0, precision)),
+            format_number(self.1, precision + 3),
+            format_hue(self.2.to_f64(), precision),
+        ];
+        write_modern_function(f, "oklch", &channels, self.3)
+    }
+}
+
+impl PartialEq for OkLch {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && self.2 == other.2
+            && (self.3 - other.3).abs() < EPSILON
+    }
+}
+
+impl TryFrom<&str> for OkLch {
+    type Error = ParseError;
+
+    /// Parses `oklch(L C h[ / alpha])`
+    ///
+    /// As in CSS, `100%` is `1` for the lightness and `0.4` for the chroma. Negative chromas are
+    /// clamped to `0`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_function(value.trim(), ColorFormat::OkLch, &["oklch"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let l = parse_channel(&components[0], ColorFormat::OkLch, "lightness", 1.0);
+        let c = parse_axis(&components[1], ColorFormat::OkLch, "chroma", 0.4);
+        let h = parse_hue(&components[2], ColorFormat::OkLch);
+        let alpha = parse_alpha(&components, 3, ColorFormat::OkLch);
+
+        match (l, c, h, alpha) {
+            (Ok(l), Ok(c), Ok(h), Ok(alpha)) => Ok(Self::new(l, c, h).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for OkLch {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<OkLab> for OkLch {
+    fn from(color: OkLab) -> Self {
+        let lch = to_polar([color.0, color.1, color.2]);
+        Self::new(lch[0], lch[1], lch[2]).with_alpha(color.3)
+    }
+}
+
+impl From<Color> for OkLch {
+    fn from(color: Color) -> Self {
+        OkLch::from(OkLab::from(color))
+    }
+}
+
+impl From<OkLch> for Color {
+    fn from(color: OkLch) -> Self {
+        Color::from(OkLab::from(color))
+    }
+}
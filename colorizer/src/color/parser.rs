@@ -0,0 +1,251 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::color::error::{ColorFormat, ParseError, ParseErrorReason};
+
+/// Raw component of a functional notation, such as the `255` in `rgb(255, 0, 0)`
+pub struct Component<'a> {
+    pub text: &'a str,
+    pub span: Range<usize>,
+}
+
+impl<'a> Component<'a> {
+    /// Trims `text`, a slice of `input`, and locates it inside `input`
+    fn new(input: &'a str, text: &'a str) -> Self {
+        let trimmed = text.trim();
+        let start = trimmed.as_ptr() as usize - input.as_ptr() as usize;
+        Self {
+            text: trimmed,
+            span: start..start + trimmed.len(),
+        }
+    }
+}
+
+/// Splits `name(a, b, c)` or `name(a b c / alpha)` into its components
+///
+/// The alpha, when present, is always the last component. `names` lists the accepted function
+/// names, such as `rgb` and `rgba`. Anything after the closing parenthesis is ignored.
+pub fn split_function<'a>(
+    input: &'a str,
+    format: ColorFormat,
+    names: &[&str],
+    expected: RangeInclusive<usize>,
+) -> Result<Vec<Component<'a>>, ParseError> {
+    let whole = 0..input.len();
+    let open = match input.find('(') {
+        Some(open)
+            if names
+                .iter()
+                .any(|name| input[..open].trim().eq_ignore_ascii_case(name)) =>
+        {
+            open
+        }
+        _ => {
+            return Err(ParseError::new(
+                Some(format),
+                whole,
+                ParseErrorReason::UnknownFormat,
+            ));
+        }
+    };
+    let close = match input[open..].find(')') {
+        Some(close) => open + close,
+        None => {
+            return Err(ParseError::new(
+                Some(format),
+                open..input.len(),
+                ParseErrorReason::MissingParen,
+            ));
+        }
+    };
+
+    let inside = &input[open + 1..close];
+    let mut components: Vec<Component> = vec![];
+    if inside.contains(',') {
+        // Legacy syntax: `rgb(255, 0, 0, 0.5)`
+        for text in inside.split(',') {
+            components.push(Component::new(input, text));
+        }
+    } else {
+        // Modern syntax: `rgb(255 0 0 / 50%)`
+        let (channels, alpha) = match inside.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha)),
+            None => (inside, None),
+        };
+        for text in channels.split_whitespace() {
+            components.push(Component::new(input, text));
+        }
+        if let Some(alpha) = alpha {
+            // The alpha after the slash can't stand in for a missing channel
+            let channel_count = *expected.start();
+            if components.len() != channel_count {
+                return Err(ParseError::new(
+                    Some(format),
+                    open..close + 1,
+                    ParseErrorReason::ComponentCount {
+                        min: channel_count,
+                        max: channel_count,
+                        found: components.len(),
+                    },
+                ));
+            }
+            components.push(Component::new(input, alpha));
+        }
+    }
+
+    if !expected.contains(&components.len()) {
+        return Err(ParseError::new(
+            Some(format),
+            open..close + 1,
+            ParseErrorReason::ComponentCount {
+                min: *expected.start(),
+                max: *expected.end(),
+                found: components.len(),
+            },
+        ));
+    }
+
+    Ok(components)
+}
+
+/// Returns the lowercase color space of a `color(space c1 c2 c3)` function
+pub fn color_space(input: &str) -> Option<String> {
+    let (_, arguments) = input.split_once('(')?;
+    let space = arguments
+        .trim_start()
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ')' || c == '/')
+        .next()?;
+    Some(space.to_lowercase())
+}
+
+/// Splits `color(space c1 c2 c3[ / alpha])`, checking the space is one of `spaces`
+///
+/// The space is the first component, followed by the channels and the optional alpha.
+pub fn split_color_function<'a>(
+    input: &'a str,
+    format: ColorFormat,
+    spaces: &[&str],
+) -> Result<Vec<Component<'a>>, ParseError> {
+    let components = split_function(input, format, &["color"], 4..=5)?;
+    let space = &components[0];
+    if !spaces
+        .iter()
+        .any(|name| space.text.eq_ignore_ascii_case(name))
+    {
+        return Err(ParseError::new(
+            Some(format),
+            space.span.clone(),
+            ParseErrorReason::UnknownColorSpace,
+        ));
+    }
+    Ok(components)
+}
+
+/// Parses a number with an optional unit, such as `12.5`, `50%` or `0.25turn`
+fn parse_number<'a>(
+    component: &Component<'a>,
+    format: ColorFormat,
+    name: &'static str,
+) -> Result<(f64, &'a str), ParseError> {
+    let text = component.text;
+    let unit_start = text
+        .rfind(|c: char| c.is_ascii_digit() || c == '.')
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let (number, unit) = text.split_at(unit_start);
+
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok((value, unit)),
+        _ => Err(invalid_number(component, format, name)),
+    }
+}
+
+fn invalid_number(component: &Component, format: ColorFormat, name: &'static str) -> ParseError {
+    ParseError::new(
+        Some(format),
+        component.span.clone(),
+        ParseErrorReason::InvalidNumber,
+    )
+    .with_component(name)
+}
+
+fn check_range(
+    value: f64,
+    max: f64,
+    component: &Component,
+    format: ColorFormat,
+    name: &'static str,
+) -> Result<f64, ParseError> {
+    if !(0.0..=max).contains(&value) {
+        return Err(ParseError::new(
+            Some(format),
+            component.span.clone(),
+            ParseErrorReason::OutOfRange {
+                value,
+                min: 0.0,
+                max,
+            },
+        )
+        .with_component(name));
+    }
+    Ok(value)
+}
+
+/// Parses a channel that is either a number in `0..=max` or a percentage of `max`
+pub fn parse_channel(
+    component: &Component,
+    format: ColorFormat,
+    name: &'static str,
+    max: f64,
+) -> Result<f64, ParseError> {
+    match parse_number(component, format, name)? {
+        (value, "") => check_range(value, max, component, format, name),
+        (value, "%") => Ok(check_range(value, 100.0, component, format, name)? / 100.0 * max),
+        _ => Err(invalid_number(component, format, name)),
+    }
+}
+
+/// Parses an unbounded number, or a percentage of `percent_of`, such as the `a` axis of Lab
+pub fn parse_axis(
+    component: &Component,
+    format: ColorFormat,
+    name: &'static str,
+    percent_of: f64,
+) -> Result<f64, ParseError> {
+    match parse_number(component, format, name)? {
+        (value, "") => Ok(value),
+        (value, "%") => Ok(value / 100.0 * percent_of),
+        _ => Err(invalid_number(component, format, name)),
+    }
+}
+
+/// Parses a hue angle in degrees, `rad`, `grad` or `turn`, returning degrees in `0..360`
+pub fn parse_hue(component: &Component, format: ColorFormat) -> Result<f64, ParseError> {
+    let degrees = match parse_number(component, format, "hue")? {
+        (value, "") | (value, "deg") => value,
+        (value, "rad") => value.to_degrees(),
+        (value, "grad") => value * 0.9,
+        (value, "turn") => value * 360.0,
+        _ => return Err(invalid_number(component, format, "hue")),
+    };
+
+    Ok(degrees.rem_euclid(360.0))
+}
+
+/// Parses the optional alpha component at `index`, either a `0..=1` number or a percentage
+///
+/// Colors without that component are fully opaque.
+pub fn parse_alpha(
+    components: &[Component],
+    index: usize,
+    format: ColorFormat,
+) -> Result<f64, ParseError> {
+    let component = match components.get(index) {
+        Some(component) => component,
+        None => return Ok(1.0),
+    };
+    match parse_number(component, format, "alpha")? {
+        (value, "") => check_range(value, 1.0, component, format, "alpha"),
+        (value, "%") => Ok(check_range(value, 100.0, component, format, "alpha")? / 100.0),
+        _ => Err(invalid_number(component, format, "alpha")),
+    }
+}
@@ -1,85 +1,192 @@
-use regex::Regex;
 use std::fmt::{Display, UpperHex};
 
 use crate::{
-    color::{ColorIntensity, HSL, HSV, RGB},
-    core::ranged::BaseNumber,
+    color::{
+        Alpha, ColorFormat, ColorIntensity, HSL, HSV, ParseError, ParseErrorReason, RGB,
+        format::{format_number, write_function},
+        parser::{parse_alpha, parse_channel, split_function},
+    },
+    core::ranged::EPSILON,
 };
 
 impl RGB {
-    pub fn new(r: u8, g: u8, b: u8) -> Self {
+    pub fn new(r: impl Into<f64>, g: impl Into<f64>, b: impl Into<f64>) -> Self {
         Self(
-            ColorIntensity::new(r as BaseNumber),
-            ColorIntensity::new(g as BaseNumber),
-            ColorIntensity::new(b as BaseNumber),
+            ColorIntensity::new(r.into()),
+            ColorIntensity::new(g.into()),
+            ColorIntensity::new(b.into()),
+            1.0,
         )
     }
 
-    pub fn to_u8_tuple(self) -> (u8, u8, u8) {
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn to_u8_tuple(&self) -> (u8, u8, u8) {
         (
-            self.0.to_f32() as u8,
-            self.1.to_f32() as u8,
-            self.2.to_f32() as u8,
+            self.0.to_f64().round() as u8,
+            self.1.to_f64().round() as u8,
+            self.2.to_f64().round() as u8,
         )
     }
 }
 
-// TODO: manage error
-impl From<String> for RGB {
-    fn from(value: String) -> Self {
-        let regex = Regex::new(r"rgb\(([0-9]+),([0-9]+),([0-9 ]+)\)").unwrap();
-        let mut numbers = value.replace(" ", "");
-        let result = regex.captures(&numbers);
-
-        match result {
-            Some(value_list) => {
-                // Numeric
-                let r: u8 = value_list[1].parse::<u8>().unwrap();
-                let g: u8 = value_list[2].parse::<u8>().unwrap();
-                let b: u8 = value_list[3].parse::<u8>().unwrap();
-                return Self::new(r, g, b);
-            }
-            None => {
-                // Hex
-                if numbers.len() == 7 {
-                    numbers.remove(0);
-                    let hex = numbers.split_at(2);
-                    let r = u8::from_str_radix(hex.0, 16).unwrap();
-                    let hex = hex.1.split_at(2);
-                    let g = u8::from_str_radix(hex.0, 16).unwrap();
-                    let b = u8::from_str_radix(hex.1, 16).unwrap();
-                    return Self::new(r, g, b);
-                }
-            }
+impl TryFrom<&str> for RGB {
+    type Error = ParseError;
+
+    /// Parses either `#RGB[A]`, `#RRGGBB[AA]`, the same digits without `#`, a `0xRRGGBB` or
+    /// `0xAARRGGBB` literal, `rgb[a](r, g, b[, a])` or `rgb(r g b[ / a])`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let value = value.trim();
+
+        if let Some(digits) = value.strip_prefix('#') {
+            return parse_hex(digits, 1).map_err(|error| error.shifted(offset));
+        }
+        if let Some(digits) = value.strip_prefix("0x").or(value.strip_prefix("0X")) {
+            return parse_hex_literal(digits).map_err(|error| error.shifted(offset));
+        }
+        if !value.is_empty() && value.chars().all(|c| c.is_ascii_hexdigit()) {
+            return parse_hex(value, 0).map_err(|error| error.shifted(offset));
         }
 
-        Self::new(0, 0, 0)
+        let components = split_function(value, ColorFormat::Rgb, &["rgb", "rgba"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+        let mut channels = [0.0; 3];
+        for (index, name) in ["red", "green", "blue"].into_iter().enumerate() {
+            channels[index] = parse_channel(&components[index], ColorFormat::Rgb, name, 255.0)
+                .map_err(|error| error.shifted(offset))?;
+        }
+        let alpha =
+            parse_alpha(&components, 3, ColorFormat::Rgb).map_err(|error| error.shifted(offset))?;
+        Ok(Self::new(channels[0], channels[1], channels[2]).with_alpha(alpha))
     }
 }
 
-impl PartialEq for RGB {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == other.1 && self.2 == other.2
+impl TryFrom<String> for RGB {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
     }
+}
+
+/// Finds the first character of `digits` that is not an hex digit
+fn check_hex_digits(digits: &str, prefix: usize) -> Result<(), ParseError> {
+    match digits.find(|c: char| !c.is_ascii_hexdigit()) {
+        Some(position) => Err(ParseError::new(
+            Some(ColorFormat::Hex),
+            position + prefix..position + prefix + 1,
+            ParseErrorReason::InvalidHex,
+        )),
+        None => Ok(()),
+    }
+}
 
-    fn ne(&self, other: &Self) -> bool {
-        self.0 != other.0 || self.1 != other.1 || self.2 != other.2
+/// Parses the digits of an hex color, spans start at the `prefix` bytes long `#`, if any
+fn parse_hex(digits: &str, prefix: usize) -> Result<RGB, ParseError> {
+    let span = 0..digits.len() + prefix;
+    check_hex_digits(digits, prefix)?;
+
+    // Shorthand forms repeat each digit: `#f80` is `#ff8800`
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits
+            .chars()
+            .map(|digit| digit.to_digit(16).unwrap() as u8 * 17)
+            .collect(),
+        6 | 8 => (0..digits.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&digits[index..index + 2], 16).unwrap())
+            .collect(),
+        length => {
+            return Err(ParseError::new(
+                Some(ColorFormat::Hex),
+                span,
+                ParseErrorReason::BadHexLength(length),
+            ));
+        }
+    };
+
+    let color = RGB::new(channels[0], channels[1], channels[2]);
+    match channels.get(3) {
+        Some(alpha) => Ok(color.with_alpha(*alpha as f64 / 255.0)),
+        None => Ok(color),
+    }
+}
+
+/// Parses the digits of a `0xRRGGBB` or `0xAARRGGBB` literal, spans start at the `0x`
+///
+/// Unlike CSS, the alpha of these literals comes first, as in Android and Win32 colors.
+fn parse_hex_literal(digits: &str) -> Result<RGB, ParseError> {
+    check_hex_digits(digits, 2)?;
+    let value = match digits.len() {
+        6 | 8 => u32::from_str_radix(digits, 16).unwrap(),
+        length => {
+            return Err(ParseError::new(
+                Some(ColorFormat::HexLiteral),
+                0..digits.len() + 2,
+                ParseErrorReason::BadHexLiteralLength(length),
+            ));
+        }
+    };
+
+    let color = RGB::new((value >> 16) & 0xFF, (value >> 8) & 0xFF, value & 0xFF);
+    match digits.len() {
+        8 => Ok(color.with_alpha((value >> 24) as f64 / 255.0)),
+        _ => Ok(color),
+    }
+}
+
+impl PartialEq for RGB {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+            && self.1 == other.1
+            && self.2 == other.2
+            && (self.3 - other.3).abs() < EPSILON
     }
 }
 
 impl Display for RGB {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "rgb({}, {}, {})", self.0, self.1, self.2)
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format_number(self.0.to_f64(), precision),
+            format_number(self.1.to_f64(), precision),
+            format_number(self.2.to_f64(), precision),
+        ];
+        write_function(f, "rgb", &channels, self.3)
     }
 }
 
 impl UpperHex for RGB {
+    /// Writes `#RRGGBB`, adding the alpha as `#RRGGBBAA` when translucent
+    ///
+    /// The alternate flag (`{:#X}`) writes the shorthand `#RGB` or `#RGBA` instead, when every
+    /// channel repeats its digit.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "#{:0>2X}{:0>2X}{:0>2X}", self.0, self.1, self.2)
+        let (r, g, b) = self.to_u8_tuple();
+        let mut channels = vec![r, g, b];
+        if self.3 < 1.0 {
+            channels.push((self.3 * 255.0).round() as u8);
+        }
+
+        write!(f, "#")?;
+        if f.alternate() && channels.iter().all(|channel| channel % 17 == 0) {
+            for channel in channels {
+                write!(f, "{:X}", channel / 17)?;
+            }
+            return Ok(());
+        }
+        for channel in channels {
+            write!(f, "{:0>2X}", channel)?;
+        }
+        Ok(())
     }
 }
 
-fn hue_to_rgb(p: f32, q: f32, mut t: f32) -> f32 {
+fn hue_to_rgb(p: f64, q: f64, mut t: f64) -> f64 {
     if t < 0.0 {
         t += 1.0;
     }
@@ -102,18 +209,17 @@ fn hue_to_rgb(p: f32, q: f32, mut t: f32) -> f32 {
 
 impl From<HSL> for RGB {
     fn from(color: HSL) -> Self {
-        let h = color.0.to_f32() / 360.0;
-        let s = color.1.to_f32() / 100.0;
-        let l = color.2.to_f32() / 100.0;
+        let h = color.0.to_f64() / 360.0;
+        let s = color.1.to_f64() / 100.0;
+        let l = color.2.to_f64() / 100.0;
 
         // No saturation
-        if color.1 == 0 {
-            let shade = l * 255.0;
-            let intensity = ColorIntensity::new(shade as i16);
-            return Self(intensity.clone(), intensity.clone(), intensity.clone());
+        if color.1 == 0.0 {
+            let intensity = ColorIntensity::new(l * 255.0);
+            return Self(intensity, intensity, intensity, color.3);
         }
 
-        let temp_1: f32 = if l < 0.5 {
+        let temp_1: f64 = if l < 0.5 {
             // Low lum
             l * (1.0 + s)
         } else {
@@ -121,79 +227,44 @@ impl From<HSL> for RGB {
             l + s - l * s
         };
 
-        let temp_2: f32 = 2.0 * l - temp_1;
+        let temp_2: f64 = 2.0 * l - temp_1;
 
         let red = hue_to_rgb(temp_2, temp_1, h + 1.0 / 3.0);
         let green = hue_to_rgb(temp_2, temp_1, h);
         let blue = hue_to_rgb(temp_2, temp_1, h - 1.0 / 3.0);
 
-        Self::new(
-            (red * 255.0).round() as u8,
-            (green * 255.0).round() as u8,
-            (blue * 255.0).round() as u8,
-        )
+        Self::new(red * 255.0, green * 255.0, blue * 255.0).with_alpha(color.3)
     }
 }
 
 impl From<HSV> for RGB {
     fn from(color: HSV) -> Self {
-        let h = color.0.to_f32() / 360.0;
-        let s = color.1.to_f32() / 100.0;
-        let v = color.2.to_f32() / 100.0;
+        let h = color.0.to_f64() / 360.0;
+        let s = color.1.to_f64() / 100.0;
+        let v = color.2.to_f64() / 100.0;
 
         if s == 0.0 {
-            let grey = (v * 255.0) as u8;
-            return Self::new(grey, grey, grey);
+            let grey = v * 255.0;
+            return Self::new(grey, grey, grey).with_alpha(color.3);
         }
 
         let i = (h * 6.0) as u8;
 
-        let f = (h * 6.0) - i as f32;
+        let f = (h * 6.0) - i as f64;
         let p = v * (1.0 - s);
         let q = v * (1.0 - s * f);
         let t = v * (1.0 - s * (1.0 - f));
         let i = i % 6;
 
-        if i == 0 {
-            return Self::new(
-                (v * 255.0).round() as u8,
-                (t * 255.0).round() as u8,
-                (p * 255.0).round() as u8,
-            );
-        }
-        if i == 1 {
-            return Self::new(
-                (q * 255.0).round() as u8,
-                (v * 255.0).round() as u8,
-                (p * 255.0).round() as u8,
-            );
-        }
-        if i == 2 {
-            return Self::new(
-                (p * 255.0).round() as u8,
-                (v * 255.0).round() as u8,
-                (t * 255.0).round() as u8,
-            );
-        }
-        if i == 3 {
-            return Self::new(
-                (p * 255.0).round() as u8,
-                (q * 255.0).round() as u8,
-                (v * 255.0).round() as u8,
-            );
-        }
-        if i == 4 {
-            return Self::new(
-                (t * 255.0).round() as u8,
-                (p * 255.0).round() as u8,
-                (v * 255.0).round() as u8,
-            );
-        }
+        let (r, g, b) = match i {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        };
 
-        return Self::new(
-            (v * 255.0).round() as u8,
-            (p * 255.0).round() as u8,
-            (q * 255.0).round() as u8,
-        );
+        Self::new(r * 255.0, g * 255.0, b * 255.0).with_alpha(color.3)
     }
 }
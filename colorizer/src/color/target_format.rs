@@ -0,0 +1,268 @@
+use std::fmt::Display;
+
+use crate::color::{
+    BlackGeneration, CMYK, Color, Gamut, GamutMapping, HSL, HSV, HWB, LCh, Lab, LinearRgb,
+    LumaStandard, NamedColor, OkLab, OkLch, RGB, RgbSpace, SignalRange, TargetFormat, WhitePoint,
+    WideRgb, XYZ, YCbCr, YIQ, YUV,
+};
+
+impl TargetFormat {
+    /// Every format, in the order they are offered to the user
+    pub fn all() -> Vec<TargetFormat> {
+        let mut formats = vec![
+            TargetFormat::Named,
+            TargetFormat::Hex,
+            TargetFormat::ShortHex,
+            TargetFormat::Rgb,
+            TargetFormat::ModernRgb,
+            TargetFormat::Hsl,
+            TargetFormat::ModernHsl,
+            TargetFormat::Hsv,
+            TargetFormat::Hwb,
+            TargetFormat::Cmyk,
+            TargetFormat::DeviceCmyk,
+            TargetFormat::Lab(WhitePoint::D50),
+            TargetFormat::Lch(WhitePoint::D50),
+            TargetFormat::OkLab,
+            TargetFormat::OkLch,
+            TargetFormat::Lab(WhitePoint::D65),
+            TargetFormat::Lch(WhitePoint::D65),
+            TargetFormat::LinearRgb,
+            TargetFormat::Xyz(WhitePoint::D65),
+            TargetFormat::Xyz(WhitePoint::D50),
+        ];
+        for standard in LumaStandard::ALL {
+            for range in [SignalRange::Limited, SignalRange::Full] {
+                formats.push(TargetFormat::YCbCr(standard, range));
+            }
+        }
+        formats.push(TargetFormat::Yuv);
+        formats.push(TargetFormat::Yiq);
+        formats.extend(RgbSpace::ALL.map(TargetFormat::Wide));
+        formats
+    }
+
+    /// Identifier of the format: its CSS function or color space name, `hex` or `named`
+    ///
+    /// `lab-d65` and `lch-d65` are not CSS, they write D65 relative values with functions of their
+    /// own.
+    pub fn name(&self) -> String {
+        match self {
+            TargetFormat::Named => String::from("named"),
+            TargetFormat::Hex => String::from("hex"),
+            TargetFormat::ShortHex => String::from("hex-short"),
+            TargetFormat::Rgb => String::from("rgb"),
+            TargetFormat::ModernRgb => String::from("rgb-modern"),
+            TargetFormat::Hsl => String::from("hsl"),
+            TargetFormat::ModernHsl => String::from("hsl-modern"),
+            TargetFormat::Hsv => String::from("hsv"),
+            TargetFormat::Hwb => String::from("hwb"),
+            TargetFormat::Cmyk => String::from("cmyk"),
+            TargetFormat::DeviceCmyk => String::from("device-cmyk"),
+            TargetFormat::Lab(WhitePoint::D50) => String::from("lab"),
+            TargetFormat::Lab(WhitePoint::D65) => String::from("lab-d65"),
+            TargetFormat::Lch(WhitePoint::D50) => String::from("lch"),
+            TargetFormat::Lch(WhitePoint::D65) => String::from("lch-d65"),
+            TargetFormat::OkLab => String::from("oklab"),
+            TargetFormat::OkLch => String::from("oklch"),
+            TargetFormat::LinearRgb => String::from("srgb-linear"),
+            TargetFormat::Xyz(WhitePoint::D50) => String::from("xyz-d50"),
+            TargetFormat::Xyz(WhitePoint::D65) => String::from("xyz-d65"),
+            TargetFormat::YCbCr(standard, SignalRange::Limited) => {
+                format!("ycbcr-{}", standard.name())
+            }
+            TargetFormat::YCbCr(standard, SignalRange::Full) => {
+                format!("ycbcr-{}-full", standard.name())
+            }
+            TargetFormat::Yuv => String::from("yuv"),
+            TargetFormat::Yiq => String::from("yiq"),
+            TargetFormat::Wide(space) => space.name().to_string(),
+        }
+    }
+
+    /// Finds a format by its identifier, ignoring case, `xyz` being `xyz-d65` as in CSS
+    pub fn from_name(name: &str) -> Option<TargetFormat> {
+        if name.eq_ignore_ascii_case("xyz") {
+            return Some(TargetFormat::Xyz(WhitePoint::D65));
+        }
+        Self::all()
+            .into_iter()
+            .find(|format| format.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Gamut the format is limited to, `None` for the unbounded ones
+    pub fn gamut(&self) -> Option<Gamut> {
+        match self {
+            TargetFormat::Named
+            | TargetFormat::Hex
+            | TargetFormat::ShortHex
+            | TargetFormat::Rgb
+            | TargetFormat::ModernRgb
+            | TargetFormat::Hsl
+            | TargetFormat::ModernHsl
+            | TargetFormat::Hsv
+            | TargetFormat::Hwb
+            | TargetFormat::Cmyk
+            | TargetFormat::DeviceCmyk => Some(Gamut::Srgb),
+            TargetFormat::Wide(space) => Some(Gamut::Wide(*space)),
+            _ => None,
+        }
+    }
+
+    /// Writes `color` in the format, alpha included when translucent
+    ///
+    /// Colors outside of the gamut of the format are brought inside of it with `mapping`, CMYK
+    /// black is computed with `generation`.
+    pub fn format(
+        &self,
+        color: &Color,
+        precision: usize,
+        mapping: GamutMapping,
+        generation: BlackGeneration,
+    ) -> String {
+        let mapped = match self.gamut() {
+            Some(gamut) => color.clone().mapped_to_gamut(gamut, mapping),
+            None => color.clone(),
+        };
+        match self {
+            TargetFormat::Named => match NamedColor::exact(color) {
+                Some(name) => name.to_string(),
+                None => NamedColor::nearest(color).0.to_string(),
+            },
+            TargetFormat::Hex => format!("{:X}", RGB::from(mapped)),
+            TargetFormat::ShortHex => format!("{:#X}", RGB::from(mapped)),
+            TargetFormat::Rgb => format!("{:.*}", precision, RGB::from(mapped)),
+            TargetFormat::ModernRgb => format!("{:#.*}", precision, RGB::from(mapped)),
+            TargetFormat::Hsl => format!("{:.*}", precision, HSL::from(mapped)),
+            TargetFormat::ModernHsl => format!("{:#.*}", precision, HSL::from(mapped)),
+            TargetFormat::Hsv => format!("{:.*}", precision, HSV::from(mapped)),
+            TargetFormat::Hwb => format!("{:.*}", precision, HWB::from(mapped)),
+            TargetFormat::Cmyk => {
+                format!("{:.*}", precision, CMYK::from_color(mapped, generation))
+            }
+            TargetFormat::DeviceCmyk => {
+                format!("{:#.*}", precision, CMYK::from_color(mapped, generation))
+            }
+            TargetFormat::Lab(white) => {
+                format!("{:.*}", precision, Lab::from(mapped).adapted_to(*white))
+            }
+            TargetFormat::Lch(white) => {
+                format!("{:.*}", precision, LCh::from(mapped).adapted_to(*white))
+            }
+            TargetFormat::OkLab => format!("{:.*}", precision, OkLab::from(mapped)),
+            TargetFormat::OkLch => format!("{:.*}", precision, OkLch::from(mapped)),
+            TargetFormat::LinearRgb => format!("{:.*}", precision, LinearRgb::from(mapped)),
+            TargetFormat::Xyz(white) => {
+                format!("{:.*}", precision, XYZ::from(mapped).adapted_to(*white))
+            }
+            TargetFormat::YCbCr(standard, range) => format!(
+                "{:.*}",
+                precision,
+                YCbCr::from_color(mapped, *standard, *range)
+            ),
+            TargetFormat::Yuv => format!("{:.*}", precision, YUV::from(mapped)),
+            TargetFormat::Yiq => format!("{:.*}", precision, YIQ::from(mapped)),
+            TargetFormat::Wide(space) => {
+                format!(
+                    "{:.*}",
+                    precision,
+                    WideRgb::from(mapped).converted_to(*space)
+                )
+            }
+        }
+    }
+
+    /// Unrounded numbers `format` writes for `color`, in the same units, alpha left out
+    ///
+    /// `None` for the formats that are not a list of numbers: names and hex.
+    pub fn components(
+        &self,
+        color: &Color,
+        mapping: GamutMapping,
+        generation: BlackGeneration,
+    ) -> Option<Vec<f64>> {
+        let mapped = match self.gamut() {
+            Some(gamut) => color.clone().mapped_to_gamut(gamut, mapping),
+            None => color.clone(),
+        };
+        let components = match self {
+            TargetFormat::Named | TargetFormat::Hex | TargetFormat::ShortHex => return None,
+            TargetFormat::Rgb | TargetFormat::ModernRgb => {
+                let rgb = RGB::from(mapped);
+                vec![rgb.0.to_f64(), rgb.1.to_f64(), rgb.2.to_f64()]
+            }
+            TargetFormat::Hsl | TargetFormat::ModernHsl => {
+                let hsl = HSL::from(mapped);
+                vec![hsl.0.to_f64(), hsl.1.to_f64(), hsl.2.to_f64()]
+            }
+            TargetFormat::Hsv => {
+                let hsv = HSV::from(mapped);
+                vec![hsv.0.to_f64(), hsv.1.to_f64(), hsv.2.to_f64()]
+            }
+            TargetFormat::Hwb => {
+                let hwb = HWB::from(mapped);
+                vec![hwb.0.to_f64(), hwb.1.to_f64(), hwb.2.to_f64()]
+            }
+            TargetFormat::Cmyk | TargetFormat::DeviceCmyk => {
+                let cmyk = CMYK::from_color(mapped, generation);
+                vec![
+                    cmyk.0.to_f64(),
+                    cmyk.1.to_f64(),
+                    cmyk.2.to_f64(),
+                    cmyk.3.to_f64(),
+                ]
+            }
+            TargetFormat::Lab(white) => {
+                let lab = Lab::from(mapped).adapted_to(*white);
+                vec![lab.0.to_f64(), lab.1, lab.2]
+            }
+            TargetFormat::Lch(white) => {
+                let lch = LCh::from(mapped).adapted_to(*white);
+                vec![lch.0.to_f64(), lch.1, lch.2.to_f64()]
+            }
+            TargetFormat::OkLab => {
+                let oklab = OkLab::from(mapped);
+                vec![oklab.0, oklab.1, oklab.2]
+            }
+            TargetFormat::OkLch => {
+                let oklch = OkLch::from(mapped);
+                vec![oklch.0, oklch.1, oklch.2.to_f64()]
+            }
+            TargetFormat::LinearRgb => {
+                let linear = LinearRgb::from(mapped);
+                vec![linear.0, linear.1, linear.2]
+            }
+            TargetFormat::Xyz(white) => {
+                let xyz = XYZ::from(mapped).adapted_to(*white);
+                vec![xyz.0, xyz.1, xyz.2]
+            }
+            TargetFormat::YCbCr(standard, range) => {
+                let ycbcr = YCbCr::from_color(mapped, *standard, *range);
+                vec![
+                    range.luma_code(ycbcr.0),
+                    range.chroma_code(ycbcr.1),
+                    range.chroma_code(ycbcr.2),
+                ]
+            }
+            TargetFormat::Yuv => {
+                let yuv = YUV::from(mapped);
+                vec![yuv.0, yuv.1, yuv.2]
+            }
+            TargetFormat::Yiq => {
+                let yiq = YIQ::from(mapped);
+                vec![yiq.0, yiq.1, yiq.2]
+            }
+            TargetFormat::Wide(space) => {
+                let wide = WideRgb::from(mapped).converted_to(*space);
+                vec![wide.0, wide.1, wide.2]
+            }
+        };
+        Some(components)
+    }
+}
+
+impl Display for TargetFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -1,6 +1,11 @@
 #[cfg(test)]
 pub mod tests {
-    use crate::color::{Color, HSL, HSV, RGB};
+    use crate::color::{
+        BlackGeneration, CMYK, Color, ColorFormat, ColorMatch, Gamut, GamutMapping, HSL, HSV, HWB,
+        Harmony, LCh, Lab, LinearRgb, LumaStandard, MixSpace, NamedColor, OkLab, OkLch, ParseError,
+        ParseErrorReason, RGB, RgbSpace, SignalRange, TargetFormat, WhitePoint, WideRgb, XYZ,
+        YCbCr, YIQ, YUV,
+    };
 
     #[test]
     fn test_color_initialization() {
@@ -29,39 +34,51 @@ pub mod tests {
         let red_rgb = RGB::new(255, 0, 0);
         let red_hsl = HSL::new(0, 100, 50);
 
-        assert_eq!(HSL::from(red_rgb), red_hsl);
+        assert_eq!(format!("{}", HSL::from(red_rgb)), format!("{}", red_hsl));
 
         let green_rgb = RGB::new(0, 255, 0);
         let green_hsl = HSL::new(120, 100, 50);
 
-        assert_eq!(HSL::from(green_rgb), green_hsl);
+        assert_eq!(
+            format!("{}", HSL::from(green_rgb)),
+            format!("{}", green_hsl)
+        );
 
         let blue_rgb = RGB::new(0, 0, 255);
         let blue_hsl = HSL::new(240, 100, 50);
 
-        assert_eq!(HSL::from(blue_rgb), blue_hsl);
+        assert_eq!(format!("{}", HSL::from(blue_rgb)), format!("{}", blue_hsl));
 
         // Variant colors
 
         let pink_rgb = RGB::new(255, 175, 204);
         let pink_hsl = HSL::new(338, 100, 84);
 
-        assert_eq!(HSL::from(pink_rgb), pink_hsl);
+        assert_eq!(format!("{}", HSL::from(pink_rgb)), format!("{}", pink_hsl));
 
         let orange_rgb = RGB::new(251, 133, 0);
         let orange_hsl = HSL::new(32, 100, 49);
 
-        assert_eq!(HSL::from(orange_rgb), orange_hsl);
+        assert_eq!(
+            format!("{}", HSL::from(orange_rgb)),
+            format!("{}", orange_hsl)
+        );
 
         let yellow_rgb = RGB::new(255, 214, 10);
         let yellow_hsl = HSL::new(50, 100, 52);
 
-        assert_eq!(HSL::from(yellow_rgb), yellow_hsl);
+        assert_eq!(
+            format!("{}", HSL::from(yellow_rgb)),
+            format!("{}", yellow_hsl)
+        );
 
         let purple_rgb = RGB::new(123, 44, 191);
         let purple_hsl = HSL::new(272, 63, 46);
 
-        assert_eq!(HSL::from(purple_rgb), purple_hsl);
+        assert_eq!(
+            format!("{}", HSL::from(purple_rgb)),
+            format!("{}", purple_hsl)
+        );
     }
 
     #[test]
@@ -69,39 +86,51 @@ pub mod tests {
         let red_rgb = RGB::new(255, 0, 0);
         let red_hsl = HSL::new(0, 100, 50);
 
-        assert_eq!(red_rgb, RGB::from(red_hsl));
+        assert_eq!(format!("{}", red_rgb), format!("{}", RGB::from(red_hsl)));
 
         let green_rgb = RGB::new(0, 255, 0);
         let green_hsl = HSL::new(120, 100, 50);
 
-        assert_eq!(green_rgb, RGB::from(green_hsl));
+        assert_eq!(
+            format!("{}", green_rgb),
+            format!("{}", RGB::from(green_hsl))
+        );
 
         let blue_rgb = RGB::new(0, 0, 255);
         let blue_hsl = HSL::new(240, 100, 50);
 
-        assert_eq!(blue_rgb, RGB::from(blue_hsl));
+        assert_eq!(format!("{}", blue_rgb), format!("{}", RGB::from(blue_hsl)));
 
         // Variant colors
 
         let pink_rgb = RGB::new(255, 173, 203);
         let pink_hsl = HSL::new(338, 100, 84);
 
-        assert_eq!(pink_rgb, RGB::from(pink_hsl));
+        assert_eq!(format!("{}", pink_rgb), format!("{}", RGB::from(pink_hsl)));
 
         let orange_rgb = RGB::new(250, 133, 0);
         let orange_hsl = HSL::new(32, 100, 49);
 
-        assert_eq!(orange_rgb, RGB::from(orange_hsl));
+        assert_eq!(
+            format!("{}", orange_rgb),
+            format!("{}", RGB::from(orange_hsl))
+        );
 
         let yellow_rgb = RGB::new(255, 214, 10);
         let yellow_hsl = HSL::new(50, 100, 52);
 
-        assert_eq!(yellow_rgb, RGB::from(yellow_hsl));
+        assert_eq!(
+            format!("{}", yellow_rgb),
+            format!("{}", RGB::from(yellow_hsl))
+        );
 
         let purple_rgb = RGB::new(122, 43, 191);
         let purple_hsl = HSL::new(272, 63, 46);
 
-        assert_eq!(purple_rgb, RGB::from(purple_hsl));
+        assert_eq!(
+            format!("{}", purple_rgb),
+            format!("{}", RGB::from(purple_hsl))
+        );
     }
 
     #[test]
@@ -133,34 +162,64 @@ pub mod tests {
     fn test_hsv_from_rgb() {
         // Base colors
         let color = RGB::new(255, 0, 0);
-        assert_eq!(HSV::from(color), HSV::new(0, 100, 100));
+        assert_eq!(
+            format!("{}", HSV::from(color)),
+            format!("{}", HSV::new(0, 100, 100))
+        );
         let color = RGB::new(0, 255, 0);
-        assert_eq!(HSV::from(color), HSV::new(120, 100, 100));
+        assert_eq!(
+            format!("{}", HSV::from(color)),
+            format!("{}", HSV::new(120, 100, 100))
+        );
         let color = RGB::new(0, 0, 255);
-        assert_eq!(HSV::from(color), HSV::new(240, 100, 100));
+        assert_eq!(
+            format!("{}", HSV::from(color)),
+            format!("{}", HSV::new(240, 100, 100))
+        );
 
         // Complex colors
         let color = RGB::new(20, 240, 100);
-        assert_eq!(HSV::from(color), HSV::new(142, 92, 94));
+        assert_eq!(
+            format!("{}", HSV::from(color)),
+            format!("{}", HSV::new(142, 92, 94))
+        );
         let color = RGB::new(220, 10, 50);
-        assert_eq!(HSV::from(color), HSV::new(349, 95, 86));
+        assert_eq!(
+            format!("{}", HSV::from(color)),
+            format!("{}", HSV::new(349, 95, 86))
+        );
     }
 
     #[test]
     fn test_rgb_from_hsv() {
         // Base colors
         let color = HSV::new(0, 100, 100);
-        assert_eq!(RGB::from(color), RGB::new(255, 0, 0));
+        assert_eq!(
+            format!("{}", RGB::from(color)),
+            format!("{}", RGB::new(255, 0, 0))
+        );
         let color = HSV::new(120, 100, 100);
-        assert_eq!(RGB::from(color), RGB::new(0, 255, 0));
+        assert_eq!(
+            format!("{}", RGB::from(color)),
+            format!("{}", RGB::new(0, 255, 0))
+        );
         let color = HSV::new(240, 100, 100);
-        assert_eq!(RGB::from(color), RGB::new(0, 0, 255));
+        assert_eq!(
+            format!("{}", RGB::from(color)),
+            format!("{}", RGB::new(0, 0, 255))
+        );
 
         // Complex colors
         let color = HSV::new(349, 95, 86);
-        assert_eq!(RGB::from(color), RGB::new(219, 11, 49));
+        assert_eq!(
+            format!("{}", RGB::from(color)),
+            format!("{}", RGB::new(219, 11, 49))
+        );
         let color = HSV::new(142, 92, 94);
-        assert_eq!(RGB::from(color), RGB::new(19, 240, 100));
+        assert_eq!(
+            format!("{}", RGB::from(color)),
+            format!("{}", RGB::new(19, 240, 100))
+        );
     }
 
     #[test]
@@ -168,110 +227,1292 @@ pub mod tests {
         // Base colors
         // HEX
         let color = "#FF0000";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(255, 0, 0));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(255, 0, 0)));
         let color = "#00FF00";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(0, 255, 0));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(0, 255, 0)));
         let color = "#0000FF";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(0, 0, 255));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(0, 0, 255)));
         // RGB
         let color = "rgb(255 ,0,0)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(255, 0, 0));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(255, 0, 0)));
         let color = "rgb(0,255, 0)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(0, 255, 0));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(0, 255, 0)));
         let color = "rgb(0 , 0, 255)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(0, 0, 255));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(0, 0, 255)));
 
         // Complex colors
         // HEX
         let color = "#Ffb703";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(255, 183, 3));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(255, 183, 3)));
         let color = "#588157";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(88, 129, 87));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(88, 129, 87)));
         let color = "#fB8500";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(251, 133, 0));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(251, 133, 0)));
         let color = "#8338eC";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(131, 56, 236));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(131, 56, 236)));
         let color = "#9D8189";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(157, 129, 137));
+        assert_eq!(
+            RGB::try_from(color.to_string()),
+            Ok(RGB::new(157, 129, 137))
+        );
         // RGB
         let color = "rgb(255, 183, 3)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(255, 183, 3));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(255, 183, 3)));
         let color = "rgb(88, 129, 87)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(88, 129, 87));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(88, 129, 87)));
         let color = "rgb(251, 133, 0)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(251, 133, 0));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(251, 133, 0)));
         let color = "rgb(131, 56, 236)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(131, 56, 236));
+        assert_eq!(RGB::try_from(color.to_string()), Ok(RGB::new(131, 56, 236)));
         let color = "rgb(157, 129, 137)";
-        assert_eq!(RGB::from(color.to_string()), RGB::new(157, 129, 137));
+        assert_eq!(
+            RGB::try_from(color.to_string()),
+            Ok(RGB::new(157, 129, 137))
+        );
     }
 
     #[test]
     fn test_hsl_string_parse() {
         // Base colors
         let color = "hsl(0, 100, 50)";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(0, 100, 50));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(0, 100, 50)));
         let color = "hsl(120, 100, 50)";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(120, 100, 50));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(120, 100, 50)));
         let color = "hsl(240, 100, 50)";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(240, 100, 50));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(240, 100, 50)));
 
         // Complex colors
         let color = "hsl(255 , 83,  3)";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(255, 83, 3));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(255, 83, 3)));
         let color = "hsl(88 , 29,87)";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(88, 29, 87));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(88, 29, 87)));
         let color = "hsl(251,33,0)";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(251, 33, 0));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(251, 33, 0)));
         let color = "hsl(131,56,36)";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(131, 56, 36));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(131, 56, 36)));
         let color = "hsl(157,29, 37 )";
-        assert_eq!(HSL::from(color.to_string()), HSL::new(157, 29, 37));
+        assert_eq!(HSL::try_from(color.to_string()), Ok(HSL::new(157, 29, 37)));
     }
 
     #[test]
     fn test_hsv_string_parse() {
         // Base colors
         let color = "hsv(0, 100, 50)";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(0, 100, 50));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(0, 100, 50)));
         let color = "hsv(120, 100, 50)";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(120, 100, 50));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(120, 100, 50)));
         let color = "hsv(240, 100, 50)";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(240, 100, 50));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(240, 100, 50)));
 
         // Complex colors
         let color = "hsv(255 , 83,  3)";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(255, 83, 3));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(255, 83, 3)));
         let color = "hsv(88 , 29,87)";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(88, 29, 87));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(88, 29, 87)));
         let color = "hsv(251,33,0)";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(251, 33, 0));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(251, 33, 0)));
         let color = "hsv(131,56,36)";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(131, 56, 36));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(131, 56, 36)));
         let color = "hsv(157,29, 37 )";
-        assert_eq!(HSV::from(color.to_string()), HSV::new(157, 29, 37));
+        assert_eq!(HSV::try_from(color.to_string()), Ok(HSV::new(157, 29, 37)));
     }
 
     #[test]
     fn test_color_string_parse() {
         let input = "test1hsv(255 , 83,  3)test2";
         let result = Color::try_parse(input.to_string());
-        assert_eq!(result, Ok(Color::from(HSV::from(input.to_string()))));
+        assert_eq!(result, Ok(Color::from(HSV::new(255, 83, 3))));
 
         let input = "test1hsj(255 , 83,  3)test2";
         let result = Color::try_parse(input.to_string());
-        assert_eq!(result, Err(()));
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                None,
+                0..input.len(),
+                ParseErrorReason::UnknownFormat
+            ))
+        );
 
         let input = "test1hsl(25a , 83,  3)test2";
         let result = Color::try_parse(input.to_string());
-        assert_eq!(result, Err(()));
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                Some(ColorFormat::Hsl),
+                9..12,
+                ParseErrorReason::InvalidNumber
+            )
+            .with_component("hue"))
+        );
 
         let input = "#afj";
         let result = Color::try_parse(input.to_string());
-        assert_eq!(result, Err(()));
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                Some(ColorFormat::Hex),
+                0..3,
+                ParseErrorReason::BadHexLength(2)
+            ))
+        );
 
         let input = "p#test2#010203j";
         let result = Color::try_parse(input.to_string());
         assert_eq!(result, Ok(Color::from(RGB::new(1, 2, 3))));
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "color: rgb(300,0,0);";
+        let result = Color::try_parse(input.to_string());
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                Some(ColorFormat::Rgb),
+                11..14,
+                ParseErrorReason::OutOfRange {
+                    value: 300.0,
+                    min: 0.0,
+                    max: 255.0
+                }
+            )
+            .with_component("red"))
+        );
+
+        let input = "rgb(10, 20, 30";
+        let result = Color::try_parse(input.to_string());
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                Some(ColorFormat::Rgb),
+                3..14,
+                ParseErrorReason::MissingParen
+            ))
+        );
+
+        let input = "hsv(10, 20)";
+        let result = Color::try_parse(input.to_string());
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                Some(ColorFormat::Hsv),
+                3..11,
+                ParseErrorReason::ComponentCount {
+                    min: 3,
+                    max: 4,
+                    found: 2
+                }
+            ))
+        );
+
+        let input = "  hsl(10, 120%, 50%)";
+        let result = Color::try_parse(input.to_string());
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                Some(ColorFormat::Hsl),
+                10..14,
+                ParseErrorReason::OutOfRange {
+                    value: 120.0,
+                    min: 0.0,
+                    max: 100.0
+                }
+            )
+            .with_component("saturation"))
+        );
+
+        let input = "#ff00f";
+        let result = Color::try_parse(input.to_string());
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                Some(ColorFormat::Hex),
+                0..6,
+                ParseErrorReason::BadHexLength(5)
+            ))
+        );
+
+        assert_eq!(
+            RGB::try_from("#ff0z00".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Hex),
+                4..5,
+                ParseErrorReason::InvalidHex
+            ))
+        );
+    }
+
+    #[test]
+    fn test_alpha_parse() {
+        let half_red = RGB::new(255, 0, 0).with_alpha(0.5);
+        assert_eq!(
+            RGB::try_from("rgba(255, 0, 0, 0.5)".to_string()),
+            Ok(half_red.clone())
+        );
+        assert_eq!(
+            RGB::try_from("rgb(255, 0, 0, 50%)".to_string()),
+            Ok(half_red.clone())
+        );
+        assert_eq!(
+            HSL::try_from("hsla(0, 100%, 50%, .5)".to_string()),
+            Ok(HSL::new(0, 100, 50).with_alpha(0.5))
+        );
+        assert_eq!(
+            HSV::try_from("hsva(0, 100%, 100%, 0.5)".to_string()),
+            Ok(HSV::new(0, 100, 100).with_alpha(0.5))
+        );
+
+        assert_eq!(
+            RGB::try_from("#ff000080".to_string()),
+            Ok(RGB::new(255, 0, 0).with_alpha(128.0 / 255.0))
+        );
+        assert_eq!(
+            RGB::try_from("#f008".to_string()),
+            Ok(RGB::new(255, 0, 0).with_alpha(136.0 / 255.0))
+        );
+        assert_eq!(RGB::try_from("#f80".to_string()), Ok(RGB::new(255, 136, 0)));
+
+        let result = Color::try_parse("background: hsla(0, 100%, 50%, 0.5);".to_string());
+        assert_eq!(result, Ok(Color::from(half_red)));
+
+        let input = "rgba(0, 0, 0, 1.5)";
+        assert_eq!(
+            Color::try_parse(input.to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Rgb),
+                14..17,
+                ParseErrorReason::OutOfRange {
+                    value: 1.5,
+                    min: 0.0,
+                    max: 1.0
+                }
+            )
+            .with_component("alpha"))
+        );
+    }
+
+    #[test]
+    fn test_alpha_conversion() {
+        let color = Color::from(RGB::new(255, 0, 0).with_alpha(0.25));
+        let hsl: HSL = color.clone().into();
+        let hsv: HSV = color.clone().into();
+
+        assert_eq!(hsl, HSL::new(0, 100, 50).with_alpha(0.25));
+        assert_eq!(hsv, HSV::new(0, 100, 100).with_alpha(0.25));
+        assert_eq!(Color::from(hsl), color);
+        assert_eq!(Color::from(hsv), color);
+    }
+
+    #[test]
+    fn test_alpha_display() {
+        let color = RGB::new(255, 0, 0).with_alpha(0.5);
+        assert_eq!(format!("{}", color), "rgba(255, 0, 0, 0.5)");
+        assert_eq!(format!("{:X}", color), "#FF000080");
+        assert_eq!(
+            format!("{}", HSL::from(color.clone())),
+            "hsla(0, 100%, 50%, 0.5)"
+        );
+        assert_eq!(format!("{}", HSV::from(color)), "hsva(0, 100%, 100%, 0.5)");
+
+        let color = RGB::new(255, 0, 0);
+        assert_eq!(format!("{}", color), "rgb(255, 0, 0)");
+        assert_eq!(format!("{:X}", color), "#FF0000");
+    }
+
+    #[test]
+    fn test_modern_syntax_parse() {
+        assert_eq!(RGB::try_from("rgb(255 0 0)"), Ok(RGB::new(255, 0, 0)));
+        assert_eq!(
+            RGB::try_from("rgb(255 0 0 / 50%)"),
+            Ok(RGB::new(255, 0, 0).with_alpha(0.5))
+        );
+        assert_eq!(
+            RGB::try_from("rgb(100% 50% 0%/.25)"),
+            Ok(RGB::new(255, 127.5, 0).with_alpha(0.25))
+        );
+        assert_eq!(
+            RGB::try_from("rgb(254.6, 0.4, 10.5)"),
+            Ok(RGB::new(254.6, 0.4, 10.5))
+        );
+
+        assert_eq!(
+            HSL::try_from("hsl(120deg 100% 50%)"),
+            Ok(HSL::new(120, 100, 50))
+        );
+        assert_eq!(
+            HSL::try_from("hsl(0.5turn 100% 50% / 0.5)"),
+            Ok(HSL::new(180, 100, 50).with_alpha(0.5))
+        );
+        assert_eq!(
+            HSL::try_from("hsl(200grad 100% 50%)"),
+            Ok(HSL::new(180, 100, 50))
+        );
+        assert_eq!(
+            HSL::try_from("hsl(3.141592653589793rad 100% 50%)"),
+            Ok(HSL::new(180, 100, 50))
+        );
+        assert_eq!(
+            HSL::try_from("hsl(-90deg 100% 50%)"),
+            Ok(HSL::new(270, 100, 50))
+        );
+        assert_eq!(
+            HSV::try_from("hsv(240 100% 100%)"),
+            Ok(HSV::new(240, 100, 100))
+        );
+
+        let input = "color: hsl(120px 100% 50%);";
+        assert_eq!(
+            Color::try_parse(input.to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Hsl),
+                11..16,
+                ParseErrorReason::InvalidNumber
+            )
+            .with_component("hue"))
+        );
+
+        let input = "rgb(255 0 / 1)";
+        assert_eq!(
+            Color::try_parse(input.to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Rgb),
+                3..14,
+                ParseErrorReason::ComponentCount {
+                    min: 3,
+                    max: 3,
+                    found: 2
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_modern_syntax_display() {
+        let color = RGB::new(255, 0, 0);
+        assert_eq!(format!("{:#}", color), "rgb(255 0 0)");
+        assert_eq!(format!("{:#}", HSL::from(color.clone())), "hsl(0 100% 50%)");
+
+        let color = color.with_alpha(0.5);
+        assert_eq!(format!("{:#}", color), "rgb(255 0 0 / 0.5)");
+        assert_eq!(
+            format!("{:#}", HSL::from(color.clone())),
+            "hsl(0 100% 50% / 0.5)"
+        );
+        assert_eq!(format!("{:#}", HSV::from(color)), "hsv(0 100% 100% / 0.5)");
+    }
+
+    #[test]
+    fn test_round_trip_precision() {
+        let colors = [
+            RGB::new(255, 175, 204),
+            RGB::new(251, 133, 0),
+            RGB::new(123, 44, 191),
+            RGB::new(1, 2, 3),
+            RGB::new(88, 129, 87).with_alpha(0.3),
+        ];
+        for rgb in colors {
+            let color = Color::from(rgb.clone());
+            let hsl: HSL = color.clone().into();
+            let hsv: HSV = color.clone().into();
+
+            assert_eq!(RGB::from(hsl), rgb);
+            assert_eq!(RGB::from(hsv), rgb);
+            assert_eq!(RGB::from(color), rgb);
+        }
+
+        // Close colors no longer collapse into the same value
+        let first = HSL::from(RGB::new(255, 175, 204));
+        let second = HSL::from(RGB::new(255, 173, 203));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let color = HSL::from(RGB::new(255, 175, 204));
+        assert_eq!(format!("{}", color), "hsl(338, 100%, 84%)");
+        assert_eq!(format!("{:.2}", color), "hsl(338.25, 100.00%, 84.31%)");
+        assert_eq!(format!("{:#.1}", color), "hsl(338.3 100.0% 84.3%)");
+
+        let color = RGB::new(127.5, 0.25, 254.9999);
+        assert_eq!(format!("{}", color), "rgb(128, 0, 255)");
+        assert_eq!(format!("{:.3}", color), "rgb(127.500, 0.250, 255.000)");
+        assert_eq!(format!("{:X}", color), "#8000FF");
+
+        assert_eq!(format!("{}", HSL::new(359.7, 50, 50)), "hsl(0, 50%, 50%)");
+        assert_eq!(
+            format!("{:.1}", HSL::new(359.7, 50, 50)),
+            "hsl(359.7, 50.0%, 50.0%)"
+        );
+    }
+
+    #[test]
+    fn test_lab_conversion() {
+        let red = Color::from(RGB::new(255, 0, 0));
+        assert_eq!(
+            format!("{:.2}", Lab::from(red.clone())),
+            "lab(54.29% 80.80 69.89)"
+        );
+        assert_eq!(
+            format!("{:.2}", LCh::from(red.clone())),
+            "lch(54.29% 106.84 40.86)"
+        );
+        assert_eq!(
+            format!("{:.2}", Lab::from(red.clone()).adapted_to(WhitePoint::D65)),
+            "lab-d65(53.24% 80.09 67.20)"
+        );
+
+        let white = Color::from(RGB::new(255, 255, 255));
+        assert_eq!(format!("{:.2}", Lab::from(white)), "lab(100.00% 0.00 0.00)");
+
+        for rgb in [
+            RGB::new(255, 0, 0),
+            RGB::new(88, 129, 87),
+            RGB::new(131, 56, 236).with_alpha(0.5),
+        ] {
+            let color = Color::from(rgb.clone());
+            assert_eq!(Color::from(Lab::from(color.clone())), color);
+            assert_eq!(Color::from(LCh::from(color.clone())), color);
+            let d65 = LCh::from(color.clone()).adapted_to(WhitePoint::D65);
+            assert_eq!(Color::from(d65), color);
+        }
+    }
+
+    #[test]
+    fn test_lab_parse() {
+        assert_eq!(
+            Lab::try_from("lab(54.29% 80.8 69.89)"),
+            Ok(Lab::new(54.29, 80.8, 69.89))
+        );
+        assert_eq!(
+            Lab::try_from("lab(50 100% -50% / 0.5)"),
+            Ok(Lab::new(50, 125, -62.5).with_alpha(0.5))
+        );
+        assert_eq!(
+            LCh::try_from("lch(54.29% 106.84 0.1135turn)"),
+            Ok(LCh::new(54.29, 106.84, 40.86))
+        );
+
+        let result = Color::try_parse("color: lch(54.2917% 106.8390 40.8526);".to_string());
+        assert_eq!(format!("{}", RGB::from(result.unwrap())), "rgb(255, 0, 0)");
+        let result = Color::try_parse("lab-d65(53.2408 80.0925 67.2032)".to_string());
+        assert_eq!(format!("{}", RGB::from(result.unwrap())), "rgb(255, 0, 0)");
+
+        let input = "lab(50% 10)";
+        assert_eq!(
+            Color::try_parse(input.to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Lab),
+                3..11,
+                ParseErrorReason::ComponentCount {
+                    min: 3,
+                    max: 4,
+                    found: 2
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_oklab_conversion() {
+        let red = Color::from(RGB::new(255, 0, 0));
+        assert_eq!(
+            format!("{}", OkLab::from(red.clone())),
+            "oklab(63% 0.225 0.126)"
+        );
+        assert_eq!(
+            format!("{:.1}", OkLch::from(red.clone())),
+            "oklch(62.8% 0.2577 29.2)"
+        );
+
+        let white = Color::from(RGB::new(255, 255, 255));
+        assert_eq!(format!("{}", OkLch::from(white)), "oklch(100% 0.000 0)");
+
+        for rgb in [
+            RGB::new(255, 0, 0),
+            RGB::new(0, 0, 255),
+            RGB::new(88, 129, 87),
+            RGB::new(131, 56, 236).with_alpha(0.5),
+        ] {
+            let color = Color::from(rgb.clone());
+            assert_eq!(Color::from(OkLab::from(color.clone())), color);
+            assert_eq!(Color::from(OkLch::from(color.clone())), color);
+        }
+    }
+
+    #[test]
+    fn test_oklab_parse() {
+        assert_eq!(
+            OkLab::try_from("oklab(0.5 0.1 -0.1)"),
+            Ok(OkLab::new(0.5, 0.1, -0.1))
+        );
+        assert_eq!(
+            OkLab::try_from("oklab(50% 25% -25% / 50%)"),
+            Ok(OkLab::new(0.5, 0.1, -0.1).with_alpha(0.5))
+        );
+        assert_eq!(
+            OkLch::try_from("oklch(62.8% 0.2577 29.23deg)"),
+            Ok(OkLch::new(0.628, 0.2577, 29.23))
+        );
+
+        let result = Color::try_parse("--brand: oklch(62.796% 0.25768 29.234);".to_string());
+        assert_eq!(format!("{}", RGB::from(result.unwrap())), "rgb(255, 0, 0)");
+
+        let input = "oklch(120% 0.1 20)";
+        assert_eq!(
+            Color::try_parse(input.to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::OkLch),
+                6..10,
+                ParseErrorReason::OutOfRange {
+                    value: 120.0,
+                    min: 0.0,
+                    max: 100.0
+                }
+            )
+            .with_component("lightness"))
+        );
+    }
+
+    #[test]
+    fn test_xyz_conversion() {
+        let white = Color::from(RGB::new(255, 255, 255));
+        assert_eq!(
+            format!("{}", XYZ::from(white.clone())),
+            "color(xyz-d65 0.950 1.000 1.089)"
+        );
+        assert_eq!(
+            format!("{}", XYZ::from(white).adapted_to(WhitePoint::D50)),
+            "color(xyz-d50 0.964 1.000 0.825)"
+        );
+
+        let red = Color::from(RGB::new(255, 0, 0));
+        assert_eq!(
+            format!("{:.1}", LinearRgb::from(red.clone())),
+            "color(srgb-linear 1.0000 0.0000 0.0000)"
+        );
+        assert_eq!(
+            format!("{}", LinearRgb::from(Color::from(RGB::new(128, 128, 128)))),
+            "color(srgb-linear 0.216 0.216 0.216)"
+        );
+
+        for rgb in [
+            RGB::new(255, 0, 0),
+            RGB::new(0, 0, 255),
+            RGB::new(88, 129, 87),
+            RGB::new(131, 56, 236).with_alpha(0.5),
+        ] {
+            let color = Color::from(rgb.clone());
+            assert_eq!(Color::from(LinearRgb::from(color.clone())), color);
+            assert_eq!(Color::from(XYZ::from(color.clone())), color);
+            let d50 = XYZ::from(color.clone()).adapted_to(WhitePoint::D50);
+            assert_eq!(Color::from(d50.clone()), color);
+            assert_eq!(d50.adapted_to(WhitePoint::D65), XYZ::from(color));
+        }
+    }
+
+    #[test]
+    fn test_color_function_parse() {
+        assert_eq!(
+            XYZ::try_from("color(xyz 0.5 0.25 100%)"),
+            Ok(XYZ::new(0.5, 0.25, 1))
+        );
+        assert_eq!(
+            XYZ::try_from("color(xyz-d65 0.5 0.25 1 / 50%)"),
+            Ok(XYZ::new(0.5, 0.25, 1).with_alpha(0.5))
+        );
+        assert_eq!(
+            LinearRgb::try_from("color(srgb-linear 1 0 0)"),
+            Ok(LinearRgb::new(1, 0, 0))
+        );
+
+        let result = Color::try_parse("fill: color(srgb-linear 1 0 0 / 0.5);".to_string());
+        assert_eq!(
+            format!("{}", RGB::from(result.unwrap())),
+            "rgba(255, 0, 0, 0.5)"
+        );
+        let result = Color::try_parse("color(xyz-d50 0.9642 1 0.8252)".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#FFFFFF");
+
+        assert_eq!(
+            Color::try_parse("color(display-p4 1 0 0)".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Color),
+                6..16,
+                ParseErrorReason::UnknownColorSpace
+            ))
+        );
+        assert_eq!(
+            Color::try_parse("color(xyz 1 0)".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Xyz),
+                5..14,
+                ParseErrorReason::ComponentCount {
+                    min: 4,
+                    max: 5,
+                    found: 3
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_wide_gamut_conversion() {
+        let red = WideRgb::from(Color::from(RGB::new(255, 0, 0)));
+        assert_eq!(format!("{}", red), "color(display-p3 0.917 0.200 0.139)");
+        assert_eq!(
+            format!("{}", red.clone().converted_to(RgbSpace::A98Rgb)),
+            "color(a98-rgb 0.859 0.000 0.000)"
+        );
+        assert_eq!(
+            format!("{}", red.clone().converted_to(RgbSpace::ProPhotoRgb)),
+            "color(prophoto-rgb 0.702 0.276 0.104)"
+        );
+        assert_eq!(
+            format!("{}", red.converted_to(RgbSpace::Rec2020)),
+            "color(rec2020 0.792 0.231 0.074)"
+        );
+
+        for rgb in [
+            RGB::new(255, 255, 255),
+            RGB::new(0, 0, 255),
+            RGB::new(88, 129, 87),
+            RGB::new(131, 56, 236).with_alpha(0.5),
+        ] {
+            let color = Color::from(rgb.clone());
+            for space in RgbSpace::ALL {
+                let wide = WideRgb::from(color.clone()).converted_to(space);
+                assert_eq!(Color::from(wide), color);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide_gamut_parse() {
+        assert_eq!(
+            WideRgb::try_from("color(display-p3 1 0.5 0 / 50%)"),
+            Ok(WideRgb::new(RgbSpace::DisplayP3, 1, 0.5, 0).with_alpha(0.5))
+        );
+        assert_eq!(
+            WideRgb::try_from("color(Rec2020 100% 0% 0%)"),
+            Ok(WideRgb::new(RgbSpace::Rec2020, 1, 0, 0))
+        );
+
+        let result = Color::try_parse("--red: color(display-p3 0.9175 0.2003 0.1386);".to_string());
+        assert_eq!(format!("{}", RGB::from(result.unwrap())), "rgb(255, 0, 0)");
+
+        let result = Color::try_parse("color(display-p3 1 0 0)".to_string()).unwrap();
+        assert!(result.0 > 1.0 && result.1 < 0.0);
+
+        assert_eq!(
+            Color::try_parse("color(a98-rgb 1 x 0)".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::WideRgb(RgbSpace::A98Rgb)),
+                16..17,
+                ParseErrorReason::InvalidNumber
+            )
+            .with_component("green"))
+        );
+    }
+
+    #[test]
+    fn test_gamut_mapping() {
+        let srgb_red = Color::from(RGB::new(255, 0, 0));
+        assert!(srgb_red.in_gamut(Gamut::Srgb));
+        assert!(srgb_red.in_gamut(Gamut::Wide(RgbSpace::DisplayP3)));
+
+        let p3_red = Color::from(WideRgb::new(RgbSpace::DisplayP3, 1, 0, 0));
+        assert!(!p3_red.in_gamut(Gamut::Srgb));
+        assert!(p3_red.in_gamut(Gamut::Wide(RgbSpace::DisplayP3)));
+        assert!(srgb_red.in_gamut(Gamut::Wide(RgbSpace::Rec2020)));
+        // Wide colors fit inside of wider gamuts despite the rounding of the conversions
+        let p3_green = Color::from(WideRgb::new(RgbSpace::DisplayP3, 0, 1, 0));
+        assert!(p3_green.in_gamut(Gamut::Wide(RgbSpace::Rec2020)));
+        assert!(p3_green.in_gamut(Gamut::Wide(RgbSpace::ProPhotoRgb)));
+        let edge = Color::try_parse("color(display-p3 0.999999 0.000001 1.000004)".to_string());
+        assert!(edge.unwrap().in_gamut(Gamut::Wide(RgbSpace::DisplayP3)));
+
+        let clipped = p3_red
+            .clone()
+            .mapped_to_gamut(Gamut::Srgb, GamutMapping::Clip);
+        assert_eq!(clipped, Color::from(RGB::new(255, 0, 0)));
+
+        let mapped = p3_red
+            .clone()
+            .mapped_to_gamut(Gamut::Srgb, GamutMapping::Perceptual);
+        assert!(mapped.in_gamut(Gamut::Srgb));
+        let (original, mapped) = (OkLch::from(p3_red.clone()), OkLch::from(mapped));
+        assert!((original.0 - mapped.0).abs() < 0.02);
+        assert!((original.2.to_f64() - mapped.2.to_f64()).abs() < 2.0);
+        assert!(mapped.1 < original.1);
+
+        // Converting to RGB maps perceptually instead of clamping each channel
+        assert_eq!(format!("{}", RGB::from(p3_red)), "rgb(255, 11, 12)");
+        let green = Color::try_parse("oklch(70% 0.4 145)".to_string()).unwrap();
+        assert!(!green.in_gamut(Gamut::Srgb));
+        assert_eq!(
+            format!("{}", RGB::from(green.clone())),
+            format!(
+                "{}",
+                RGB::from(green.mapped_to_gamut(Gamut::Srgb, GamutMapping::Perceptual))
+            )
+        );
+
+        let translucent = Color::from(WideRgb::new(RgbSpace::Rec2020, 0, 1, 0).with_alpha(0.5));
+        let mapped =
+            translucent.mapped_to_gamut(Gamut::Wide(RgbSpace::DisplayP3), GamutMapping::Perceptual);
+        assert!(mapped.in_gamut(Gamut::Wide(RgbSpace::DisplayP3)));
+        assert_eq!(mapped.alpha(), 0.5);
+    }
+
+    #[test]
+    fn test_hwb() {
+        let red = Color::from(RGB::new(255, 0, 0));
+        assert_eq!(format!("{}", HWB::from(red.clone())), "hwb(0 0% 0%)");
+        assert_eq!(Color::from(HWB::new(0, 0, 0)), red);
+
+        let sage = Color::from(RGB::new(88, 129, 87));
+        assert_eq!(
+            format!("{:.1}", HWB::from(sage.clone())),
+            "hwb(118.6 34.1% 49.4%)"
+        );
+        assert_eq!(Color::from(HWB::from(sage.clone())), sage);
+        assert_eq!(
+            format!(
+                "{}",
+                HWB::from(Color::from(RGB::new(131, 56, 236).with_alpha(0.5)))
+            ),
+            "hwb(265 22% 7% / 0.5)"
+        );
+
+        // Whiteness and blackness over 100% make a gray
+        assert_eq!(
+            format!("{}", RGB::from(Color::from(HWB::new(90, 60, 60)))),
+            "rgb(128, 128, 128)"
+        );
+
+        assert_eq!(
+            HWB::try_from("hwb(120deg 20% 30% / 50%)"),
+            Ok(HWB::new(120, 20, 30).with_alpha(0.5))
+        );
+        let result = Color::try_parse("color: hwb(240 0% 0%);".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#0000FF");
+        assert_eq!(
+            Color::try_parse("hwb(0 120% 0%)".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Hwb),
+                6..10,
+                ParseErrorReason::OutOfRange {
+                    value: 120.0,
+                    min: 0.0,
+                    max: 100.0
+                }
+            )
+            .with_component("whiteness"))
+        );
+    }
+
+    #[test]
+    fn test_cmyk_conversion() {
+        let red = Color::from(RGB::new(255, 0, 0));
+        assert_eq!(
+            format!("{}", CMYK::from(red.clone())),
+            "cmyk(0%, 100%, 100%, 0%)"
+        );
+        assert_eq!(
+            format!("{:#}", CMYK::from(red)),
+            "device-cmyk(0% 100% 100% 0%)"
+        );
+
+        let sage = Color::from(RGB::new(88, 129, 87));
+        assert_eq!(
+            format!("{}", CMYK::from(sage.clone())),
+            "cmyk(32%, 0%, 33%, 49%)"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                CMYK::from_color(
+                    sage.clone(),
+                    BlackGeneration::GrayComponentReplacement { amount: 0.5 }
+                )
+            ),
+            "cmyk(54%, 33%, 55%, 25%)"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                CMYK::from_color(
+                    sage.clone(),
+                    BlackGeneration::UnderColorRemoval { start: 0.3 }
+                )
+            ),
+            "cmyk(60%, 41%, 60%, 14%)"
+        );
+
+        for generation in [
+            BlackGeneration::Naive,
+            BlackGeneration::UnderColorRemoval { start: 0.2 },
+            BlackGeneration::GrayComponentReplacement { amount: 0.3 },
+        ] {
+            for rgb in [
+                RGB::new(255, 255, 255),
+                RGB::new(0, 0, 0),
+                RGB::new(88, 129, 87),
+                RGB::new(131, 56, 236).with_alpha(0.5),
+            ] {
+                let color = Color::from(rgb.clone());
+                let cmyk = CMYK::from_color(color.clone(), generation);
+                assert_eq!(Color::from(cmyk), color);
+            }
+        }
+        assert_eq!(
+            RGB::from(CMYK::from(RGB::new(12, 34, 56))),
+            RGB::new(12, 34, 56)
+        );
+    }
+
+    #[test]
+    fn test_cmyk_parse() {
+        assert_eq!(
+            CMYK::try_from("cmyk(0%, 100%, 100%, 0%)"),
+            Ok(CMYK::new(0, 100, 100, 0))
+        );
+        assert_eq!(
+            CMYK::try_from("cmyka(10, 20, 30, 40, 0.5)"),
+            Ok(CMYK::new(10, 20, 30, 40).with_alpha(0.5))
+        );
+        assert_eq!(
+            CMYK::try_from("device-cmyk(0.1 0.2 30% 0.4 / 50%)"),
+            Ok(CMYK::new(10, 20, 30, 40).with_alpha(0.5))
+        );
+
+        let result = Color::try_parse("background: device-cmyk(0 1 1 0);".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#FF0000");
+        assert_eq!(
+            Color::try_parse("device-cmyk(0 2 1 0)".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Cmyk),
+                14..15,
+                ParseErrorReason::OutOfRange {
+                    value: 2.0,
+                    min: 0.0,
+                    max: 1.0
+                }
+            )
+            .with_component("magenta"))
+        );
+    }
+
+    #[test]
+    fn test_video_encodings() {
+        let white = Color::from(RGB::new(255, 255, 255));
+        let red = Color::from(RGB::new(255, 0, 0));
+        assert_eq!(
+            format!("{}", YCbCr::from(white.clone())),
+            "ycbcr-bt709(235 128 128)"
+        );
+        assert_eq!(
+            format!("{:.1}", YCbCr::from(red.clone())),
+            "ycbcr-bt709(62.6 102.3 240.0)"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                YCbCr::from_color(red.clone(), LumaStandard::Bt601, SignalRange::Full)
+            ),
+            "ycbcr-bt601-full(76 85 255)"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                YCbCr::from_color(white.clone(), LumaStandard::Bt2020, SignalRange::Full)
+            ),
+            "ycbcr-bt2020-full(255 128 128)"
+        );
+        assert_eq!(
+            format!("{}", YUV::from(red.clone())),
+            "yuv(0.299 -0.147 0.615)"
+        );
+        assert_eq!(
+            format!("{}", YIQ::from(red.clone())),
+            "yiq(0.299 0.596 0.211)"
+        );
+
+        for rgb in [
+            RGB::new(255, 0, 0),
+            RGB::new(0, 0, 255),
+            RGB::new(88, 129, 87),
+            RGB::new(131, 56, 236).with_alpha(0.5),
+        ] {
+            let color = Color::from(rgb.clone());
+            for standard in LumaStandard::ALL {
+                for range in [SignalRange::Full, SignalRange::Limited] {
+                    let ycbcr = YCbCr::from_color(color.clone(), standard, range);
+                    assert_eq!(Color::from(ycbcr), color);
+                }
+            }
+            assert_eq!(Color::from(YUV::from(color.clone())), color);
+            assert_eq!(Color::from(YIQ::from(color.clone())), color);
+        }
+    }
+
+    #[test]
+    fn test_video_encodings_parse() {
+        assert_eq!(
+            YCbCr::try_from("ycbcr-bt601(235 128 128 / 0.5)"),
+            Ok(
+                YCbCr::new(LumaStandard::Bt601, SignalRange::Limited, 235, 128, 128)
+                    .with_alpha(0.5)
+            )
+        );
+        assert_eq!(
+            YCbCr::try_from("YCbCr-BT2020-full(255, 128, 128)"),
+            Ok(YCbCr::new(
+                LumaStandard::Bt2020,
+                SignalRange::Full,
+                255,
+                128,
+                128
+            ))
+        );
+        assert_eq!(
+            YUV::try_from("yuv(0.5 -0.1 0.1)"),
+            Ok(YUV::new(0.5, -0.1, 0.1))
+        );
+        assert_eq!(YIQ::try_from("yiq(50% 0.1 0)"), Ok(YIQ::new(0.5, 0.1, 0)));
+
+        let result = Color::try_parse("pixel: ycbcr-bt709(62.56 102.34 240)".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#FF0000");
+        let result = Color::try_parse("yuv(0.299 -0.147 0.615)".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#FF0000");
+        let result = Color::try_parse("yiq(1 0 0)".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#FFFFFF");
+
+        assert_eq!(
+            Color::try_parse("ycbcr-bt709(300 128 128)".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::YCbCr),
+                12..15,
+                ParseErrorReason::OutOfRange {
+                    value: 300.0,
+                    min: 0.0,
+                    max: 255.0
+                }
+            )
+            .with_component("luma"))
+        );
+    }
+
+    #[test]
+    fn test_named_colors() {
+        let result = Color::try_parse("rebeccapurple".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#663399");
+        let result = Color::try_parse("border: 1px solid Tomato;".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#FF6347");
+        let result = Color::try_parse("TRANSPARENT".to_string());
+        assert_eq!(
+            format!("{}", RGB::from(result.unwrap())),
+            "rgba(0, 0, 0, 0)"
+        );
+
+        // Hex and functional notations come first
+        let result = Color::try_parse("red #00F".to_string());
+        assert_eq!(format!("{:X}", RGB::from(result.unwrap())), "#0000FF");
+        assert_eq!(
+            NamedColor::try_from("notacolor"),
+            Err(ParseError::new(
+                Some(ColorFormat::Named),
+                0..9,
+                ParseErrorReason::UnknownName
+            ))
+        );
+        assert_eq!(
+            Color::try_parse("hello world".to_string()),
+            Err(ParseError::new(
+                None,
+                0..11,
+                ParseErrorReason::UnknownFormat
+            ))
+        );
+    }
+
+    #[test]
+    fn test_named_color_lookup() {
+        let tomato = Color::from(RGB::new(255, 99, 71));
+        assert_eq!(format!("{}", NamedColor::exact(&tomato).unwrap()), "tomato");
+        let gray = Color::from(RGB::new(128, 128, 128));
+        assert_eq!(format!("{}", NamedColor::exact(&gray).unwrap()), "gray");
+        let clear = Color::from(RGB::new(255, 0, 0).with_alpha(0.0));
+        assert_eq!(
+            format!("{}", NamedColor::exact(&clear).unwrap()),
+            "transparent"
+        );
+        assert_eq!(
+            NamedColor::exact(&Color::from(RGB::new(255, 99, 71).with_alpha(0.5))),
+            None
+        );
+
+        let almost_tomato = Color::from(RGB::new(250, 100, 70));
+        assert_eq!(NamedColor::exact(&almost_tomato), None);
+        let (name, distance) = NamedColor::nearest(&almost_tomato);
+        assert_eq!(format!("{}", name), "tomato");
+        assert!(distance > 0.0 && distance < 0.02);
+    }
+
+    #[test]
+    fn test_hex_forms() {
+        for (input, expected) in [
+            ("#f00", "#FF0000"),
+            ("#F008", "#FF000088"),
+            ("#ff8800", "#FF8800"),
+            ("#ff880080", "#FF880080"),
+            ("ff8800", "#FF8800"),
+            ("  f80 ", "#FF8800"),
+            ("int color = 0xFF8800;", "#FF8800"),
+            ("0x80FF8800", "#FF880080"),
+        ] {
+            let result = Color::try_parse(input.to_string());
+            assert_eq!(format!("{:X}", result.unwrap()), expected, "{}", input);
+        }
+
+        assert_eq!(
+            Color::try_parse("0xFF88".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::HexLiteral),
+                0..6,
+                ParseErrorReason::BadHexLiteralLength(4)
+            ))
+        );
+        assert_eq!(
+            Color::try_parse("let color = 0xFF88G0;".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Hex),
+                18..19,
+                ParseErrorReason::InvalidHex
+            ))
+        );
+        assert_eq!(
+            Color::try_parse("ff880".to_string()),
+            Err(ParseError::new(
+                Some(ColorFormat::Hex),
+                0..5,
+                ParseErrorReason::BadHexLength(5)
+            ))
+        );
+        // Bare digits inside of other text are not colors
+        assert_eq!(
+            Color::try_parse("background ff8800".to_string()),
+            Err(ParseError::new(
+                None,
+                0..17,
+                ParseErrorReason::UnknownFormat
+            ))
+        );
+    }
+
+    #[test]
+    fn test_short_hex() {
+        assert_eq!(format!("{:#X}", Color::from(RGB::new(255, 136, 0))), "#F80");
+        assert_eq!(
+            format!("{:#X}", Color::from(RGB::new(255, 136, 0).with_alpha(0.2))),
+            "#F803"
+        );
+        assert_eq!(
+            format!("{:#X}", Color::from(RGB::new(255, 136, 1))),
+            "#FF8801"
+        );
+        assert_eq!(
+            format!("{:#X}", Color::from(RGB::new(255, 136, 0).with_alpha(0.5))),
+            "#FF880080"
+        );
+    }
+
+    #[test]
+    fn test_find_all() {
+        let input =
+            ".button { color: #fff; background: rgb(255 0 0 / 50%); border: 1px solid tomato; }";
+        let found = Color::find_all(input);
+        assert_eq!(found.len(), 3);
+        assert_eq!(
+            found[0],
+            ColorMatch {
+                color: Color::from(RGB::new(255, 255, 255)),
+                format: ColorFormat::Hex,
+                span: 17..21
+            }
+        );
+        assert_eq!(&input[found[1].span.clone()], "rgb(255 0 0 / 50%)");
+        assert_eq!(found[1].format, ColorFormat::Rgb);
+        assert_eq!(
+            found[1].color,
+            Color::from(RGB::new(255, 0, 0).with_alpha(0.5))
+        );
+        assert_eq!(&input[found[2].span.clone()], "tomato");
+        assert_eq!(found[2].format, ColorFormat::Named);
+
+        // Invalid candidates are skipped
+        let input = "WARN fill=#12 stroke=0xFF336699 hsl(400, 50%, 50%) color(display-p3 1 0 0)";
+        let found: Vec<(&str, ColorFormat)> = Color::find_all(input)
+            .into_iter()
+            .map(|found| (&input[found.span], found.format))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("0xFF336699", ColorFormat::HexLiteral),
+                ("hsl(400, 50%, 50%)", ColorFormat::Hsl),
+                (
+                    "color(display-p3 1 0 0)",
+                    ColorFormat::WideRgb(RgbSpace::DisplayP3)
+                ),
+            ]
+        );
+
+        assert_eq!(Color::find_all("no colors here"), []);
+        assert_eq!(Color::find_all(""), []);
+        assert_eq!(Color::find_all("f80")[0].span, 0..3);
+    }
+
+    #[test]
+    fn test_find_all_skips_identifiers() {
+        let input = ".text-red-500 { color: var(--brand-blue, blue); fill: $white; }";
+        let found: Vec<&str> = Color::find_all(input)
+            .into_iter()
+            .map(|found| &input[found.span])
+            .collect();
+        assert_eq!(found, ["blue"]);
+    }
+
+    #[test]
+    fn test_find_all_skips_hex_identifiers() {
+        let input = "#fab-button, #badge, #add_icon { color: #fab; border: 1px solid #bad }";
+        let found: Vec<&str> = Color::find_all(input)
+            .into_iter()
+            .map(|found| &input[found.span])
+            .collect();
+        assert_eq!(found, ["#fab", "#bad"]);
+    }
+
+    #[test]
+    fn test_target_format() {
+        for format in TargetFormat::all() {
+            assert_eq!(TargetFormat::from_name(&format.name()), Some(format));
+        }
+        assert_eq!(
+            TargetFormat::from_name("XYZ"),
+            Some(TargetFormat::Xyz(WhitePoint::D65))
+        );
+        assert_eq!(
+            TargetFormat::from_name("ycbcr-bt709-full"),
+            Some(TargetFormat::YCbCr(LumaStandard::Bt709, SignalRange::Full))
+        );
+        assert_eq!(TargetFormat::from_name("hsx"), None);
+
+        let format = |format: TargetFormat, color: &Color| {
+            format.format(color, 0, GamutMapping::Perceptual, BlackGeneration::Naive)
+        };
+        let tomato = Color::from(RGB::new(255, 99, 71).with_alpha(0.5));
+        assert_eq!(format(TargetFormat::Hex, &tomato), "#FF634780");
+        assert_eq!(
+            format(TargetFormat::ModernRgb, &tomato),
+            "rgb(255 99 71 / 0.5)"
+        );
+        assert_eq!(format(TargetFormat::Named, &tomato), "tomato");
+        assert_eq!(format(TargetFormat::ShortHex, &tomato), "#FF634780");
+        assert_eq!(
+            format(TargetFormat::ShortHex, &Color::from(RGB::new(255, 0, 0))),
+            "#F00"
+        );
+
+        // Limited formats are mapped into their gamut
+        let p3_red = Color::from(WideRgb::new(RgbSpace::DisplayP3, 1, 0, 0));
+        assert_eq!(format(TargetFormat::Rgb, &p3_red), "rgb(255, 11, 12)");
+        assert_eq!(
+            format(TargetFormat::Wide(RgbSpace::DisplayP3), &p3_red),
+            "color(display-p3 1.000 0.000 0.000)"
+        );
+        assert_eq!(TargetFormat::Lab(WhitePoint::D50).gamut(), None);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let white = Color::from(RGB::new(255, 255, 255));
+        let black = Color::from(RGB::new(0, 0, 0));
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < 1e-9);
+
+        let gray = Color::try_parse(String::from("#777777")).unwrap();
+        assert!((gray.contrast_ratio(&white) - 4.48).abs() < 0.005);
+
+        // Half transparent black over white shows as mid gray
+        let translucent = Color::try_parse(String::from("rgb(0 0 0 / 50%)")).unwrap();
+        let shown = translucent.composited_over(&white);
+        assert_eq!(shown, Color::from(RGB::new(127.5, 127.5, 127.5)));
+        assert!((translucent.contrast_ratio(&white) - shown.contrast_ratio(&white)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mix() {
+        let red = Color::from(RGB::new(255, 0, 0));
+        let blue = Color::from(RGB::new(0, 0, 255));
+        assert_eq!(red.mix(&blue, 0.0, MixSpace::OkLab), red);
+        assert_eq!(red.mix(&blue, 1.0, MixSpace::OkLab), blue);
+        assert_eq!(
+            red.mix(&blue, 0.5, MixSpace::Srgb),
+            Color::from(RGB::new(127.5, 0.0, 127.5))
+        );
+
+        // Alpha is premultiplied, so a transparent color does not darken the mix
+        let transparent = Color::from(RGB::new(0, 0, 0).with_alpha(0.0));
+        let mixed = red.mix(&transparent, 0.5, MixSpace::Srgb);
+        assert_eq!(mixed, Color::from(RGB::new(255, 0, 0).with_alpha(0.5)));
+
+        // Hues are interpolated along the shorter arc, and a gray takes the hue of the other color
+        let white = Color::from(RGB::new(255, 255, 255));
+        let mixed = OkLch::from(red.mix(&white, 0.5, MixSpace::OkLch));
+        let red_hue = OkLch::from(red.clone()).2.to_f64();
+        assert!((mixed.2.to_f64() - red_hue).abs() < 1e-6);
+
+        assert_eq!(MixSpace::from_name("OKLCH"), Some(MixSpace::OkLch));
+        assert_eq!(
+            MixSpace::from_name("srgb-linear"),
+            Some(MixSpace::LinearRgb)
+        );
+        assert_eq!(MixSpace::from_name("hsl"), None);
+    }
+
+    #[test]
+    fn test_harmony() {
+        let color = Color::try_parse(String::from("#3366cc")).unwrap();
+        let lch = OkLch::from(color.clone());
+        for harmony in Harmony::ALL {
+            let colors = color.harmony(harmony);
+            assert_eq!(colors[0], color, "{} starts with the color", harmony);
+            for other in &colors {
+                let other = OkLch::from(other.clone());
+                assert!(
+                    (other.0 - lch.0).abs() < 1e-6,
+                    "{} keeps the lightness",
+                    harmony
+                );
+            }
+        }
+        assert_eq!(color.harmony(Harmony::Complementary).len(), 2);
+        assert_eq!(color.harmony(Harmony::Tetradic).len(), 4);
+        assert_eq!(
+            Harmony::from_name("split-complementary"),
+            Some(Harmony::SplitComplementary)
+        );
+
+        let shades = color.shades(5);
+        assert_eq!(shades.len(), 5);
+        let lightness: Vec<f64> = shades
+            .iter()
+            .map(|shade| OkLch::from(shade.clone()).0)
+            .collect();
+        assert!(lightness.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(color.shades(0).is_empty());
+    }
 }
@@ -0,0 +1,290 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, ParseError, RgbSpace, WhitePoint, WideRgb, XYZ,
+        cie::{Matrix, Vector, linear_to_srgb, multiply, srgb_to_linear},
+        format::{format_number, write_modern_function},
+        parser::{color_space, parse_alpha, parse_axis, split_color_function},
+    },
+    core::ranged::EPSILON,
+};
+
+/// Linear RGB to CIE XYZ matrices from CSS Color 4, each inverse computed exactly from its
+/// forward matrix so round trips don't drift
+const LINEAR_P3_TO_XYZ_D65: Matrix = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+const XYZ_D65_TO_LINEAR_P3: Matrix = [
+    [2.493496911941425, -0.9313836179191238, -0.40271078445071684],
+    [-0.8294889695615747, 1.762664060318346, 0.023624685841943584],
+    [
+        0.03584583024378436,
+        -0.07617238926804178,
+        0.9568845240076872,
+    ],
+];
+const LINEAR_A98_TO_XYZ_D65: Matrix = [
+    [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+    [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+    [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+const XYZ_D65_TO_LINEAR_A98: Matrix = [
+    [2.0415879038107465, -0.5650069742788597, -0.3447313507783296],
+    [-0.9692436362808795, 1.8759675015077202, 0.04155505740717559],
+    [
+        0.013444280632031146,
+        -0.11836239223101838,
+        1.0151749943912054,
+    ],
+];
+const LINEAR_PROPHOTO_TO_XYZ_D50: Matrix = [
+    [0.7977666449006423, 0.13518129740053308, 0.0313477341283922],
+    [0.2880748288194013, 0.711835234241873, 0.00008993693872564],
+    [0.0, 0.0, 0.8251046025104602],
+];
+const XYZ_D50_TO_LINEAR_PROPHOTO: Matrix = [
+    [
+        1.3457868816471585,
+        -0.25557208737979464,
+        -0.051101864975545266,
+    ],
+    [
+        -0.5446307051249019,
+        1.5082477428451468,
+        0.020527447436421403,
+    ],
+    [0.0, 0.0, 1.2119675456389452],
+];
+const LINEAR_REC2020_TO_XYZ_D65: Matrix = [
+    [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+    [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+    [0.0, 0.028072693049087428, 1.060985057710791],
+];
+const XYZ_D65_TO_LINEAR_REC2020: Matrix = [
+    [
+        1.7166511879712674,
+        -0.35567078377639233,
+        -0.25336628137365974,
+    ],
+    [
+        -0.6666843518324892,
+        1.6164812366349395,
+        0.015768545813911142,
+    ],
+    [
+        0.017639857445310866,
+        -0.04277061325780854,
+        0.9421031212354738,
+    ],
+];
+
+/// Rec. 2020 transfer function constants
+const REC2020_ALPHA: f64 = 1.09929682680944;
+const REC2020_BETA: f64 = 0.018053968510807;
+
+impl RgbSpace {
+    pub const ALL: [RgbSpace; 4] = [
+        RgbSpace::DisplayP3,
+        RgbSpace::A98Rgb,
+        RgbSpace::ProPhotoRgb,
+        RgbSpace::Rec2020,
+    ];
+
+    /// Identifier of the space inside CSS `color()`
+    pub fn name(&self) -> &'static str {
+        match self {
+            RgbSpace::DisplayP3 => "display-p3",
+            RgbSpace::A98Rgb => "a98-rgb",
+            RgbSpace::ProPhotoRgb => "prophoto-rgb",
+            RgbSpace::Rec2020 => "rec2020",
+        }
+    }
+
+    /// Finds a space by its CSS identifier, ignoring case
+    pub fn from_name(name: &str) -> Option<RgbSpace> {
+        Self::ALL
+            .into_iter()
+            .find(|space| space.name().eq_ignore_ascii_case(name))
+    }
+
+    /// White point the space's primaries are defined against
+    pub fn white(&self) -> WhitePoint {
+        match self {
+            RgbSpace::ProPhotoRgb => WhitePoint::D50,
+            _ => WhitePoint::D65,
+        }
+    }
+
+    fn linear_to_xyz(self) -> &'static Matrix {
+        match self {
+            RgbSpace::DisplayP3 => &LINEAR_P3_TO_XYZ_D65,
+            RgbSpace::A98Rgb => &LINEAR_A98_TO_XYZ_D65,
+            RgbSpace::ProPhotoRgb => &LINEAR_PROPHOTO_TO_XYZ_D50,
+            RgbSpace::Rec2020 => &LINEAR_REC2020_TO_XYZ_D65,
+        }
+    }
+
+    fn xyz_to_linear(self) -> &'static Matrix {
+        match self {
+            RgbSpace::DisplayP3 => &XYZ_D65_TO_LINEAR_P3,
+            RgbSpace::A98Rgb => &XYZ_D65_TO_LINEAR_A98,
+            RgbSpace::ProPhotoRgb => &XYZ_D50_TO_LINEAR_PROPHOTO,
+            RgbSpace::Rec2020 => &XYZ_D65_TO_LINEAR_REC2020,
+        }
+    }
+
+    /// Removes the transfer function of the space, keeping the sign of out of gamut values
+    fn decode(self, channel: f64) -> f64 {
+        let magnitude = channel.abs();
+        match self {
+            RgbSpace::DisplayP3 => srgb_to_linear(channel),
+            RgbSpace::A98Rgb => channel.signum() * magnitude.powf(563.0 / 256.0),
+            RgbSpace::ProPhotoRgb if magnitude <= 16.0 / 512.0 => channel / 16.0,
+            RgbSpace::ProPhotoRgb => channel.signum() * magnitude.powf(1.8),
+            RgbSpace::Rec2020 if magnitude < REC2020_BETA * 4.5 => channel / 4.5,
+            RgbSpace::Rec2020 => {
+                channel.signum()
+                    * ((magnitude + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+            }
+        }
+    }
+
+    /// Applies the transfer function of the space, keeping the sign of out of gamut values
+    fn encode(self, channel: f64) -> f64 {
+        let magnitude = channel.abs();
+        match self {
+            RgbSpace::DisplayP3 => linear_to_srgb(channel),
+            RgbSpace::A98Rgb => channel.signum() * magnitude.powf(256.0 / 563.0),
+            RgbSpace::ProPhotoRgb if magnitude < 1.0 / 512.0 => channel * 16.0,
+            RgbSpace::ProPhotoRgb => channel.signum() * magnitude.powf(1.0 / 1.8),
+            RgbSpace::Rec2020 if magnitude <= REC2020_BETA => channel * 4.5,
+            RgbSpace::Rec2020 => {
+                channel.signum() * (REC2020_ALPHA * magnitude.powf(0.45) - (REC2020_ALPHA - 1.0))
+            }
+        }
+    }
+}
+
+impl Display for RgbSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl WideRgb {
+    pub fn new(space: RgbSpace, r: impl Into<f64>, g: impl Into<f64>, b: impl Into<f64>) -> Self {
+        Self(r.into(), g.into(), b.into(), 1.0, space)
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Converts XYZ values into `space`, adapting them to its white point first
+    pub fn from_xyz(color: XYZ, space: RgbSpace) -> Self {
+        let xyz = color.adapted_to(space.white());
+        let linear: Vector = multiply(space.xyz_to_linear(), [xyz.0, xyz.1, xyz.2]);
+        let rgb = linear.map(|channel| space.encode(channel));
+        Self(rgb[0], rgb[1], rgb[2], xyz.3, space)
+    }
+
+    /// Expresses the same color in another wide gamut space
+    pub fn converted_to(self, space: RgbSpace) -> Self {
+        Self::from_xyz(XYZ::from(self), space)
+    }
+}
+
+impl Display for WideRgb {
+    /// Always uses the CSS Color 4 `color(display-p3 r g b)` syntax
+    ///
+    /// Channels go from `0` to `1`, so they get 3 more decimals than the requested precision.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            self.4.name().to_string(),
+            format_number(self.0, precision + 3),
+            format_number(self.1, precision + 3),
+            format_number(self.2, precision + 3),
+        ];
+        write_modern_function(f, "color", &channels, self.3)
+    }
+}
+
+impl PartialEq for WideRgb {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+            && self.4 == other.4
+    }
+}
+
+impl TryFrom<&str> for WideRgb {
+    type Error = ParseError;
+
+    /// Parses `color(space r g b[ / alpha])` for any of the wide gamut spaces, where `100%` is `1`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let value = value.trim();
+        let format = match color_space(value).and_then(|name| RgbSpace::from_name(&name)) {
+            Some(space) => ColorFormat::WideRgb(space),
+            None => ColorFormat::Color,
+        };
+        let names = RgbSpace::ALL.map(|space| space.name());
+        let components =
+            split_color_function(value, format, &names).map_err(|error| error.shifted(offset))?;
+        let space = match format {
+            ColorFormat::WideRgb(space) => space,
+            _ => unreachable!("split_color_function only accepts known spaces"),
+        };
+
+        let r = parse_axis(&components[1], format, "red", 1.0);
+        let g = parse_axis(&components[2], format, "green", 1.0);
+        let b = parse_axis(&components[3], format, "blue", 1.0);
+        let alpha = parse_alpha(&components, 4, format);
+
+        match (r, g, b, alpha) {
+            (Ok(r), Ok(g), Ok(b), Ok(alpha)) => Ok(Self::new(space, r, g, b).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for WideRgb {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<WideRgb> for XYZ {
+    /// Keeps the white point of the space, ProPhoto values stay relative to D50
+    fn from(color: WideRgb) -> Self {
+        let space = color.4;
+        let linear = [color.0, color.1, color.2].map(|channel| space.decode(channel));
+        let xyz = multiply(space.linear_to_xyz(), linear);
+        XYZ(xyz[0], xyz[1], xyz[2], color.3, space.white())
+    }
+}
+
+impl From<Color> for WideRgb {
+    /// Converts to Display P3, the most common wide gamut space
+    fn from(color: Color) -> Self {
+        WideRgb::from_xyz(XYZ::from(color), RgbSpace::DisplayP3)
+    }
+}
+
+impl From<WideRgb> for Color {
+    fn from(color: WideRgb) -> Self {
+        Color::from(XYZ::from(color))
+    }
+}
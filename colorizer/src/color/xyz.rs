@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, LinearRgb, ParseError, WhitePoint, XYZ,
+        cie::adapt,
+        format::{format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, split_color_function},
+    },
+    core::ranged::EPSILON,
+};
+
+impl XYZ {
+    /// Creates an XYZ color relative to D65, the white point of CSS `xyz`
+    pub fn new(x: impl Into<f64>, y: impl Into<f64>, z: impl Into<f64>) -> Self {
+        Self(x.into(), y.into(), z.into(), 1.0, WhitePoint::D65)
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Expresses the same color relative to another white point, using Bradford adaptation
+    pub fn adapted_to(self, white: WhitePoint) -> Self {
+        let xyz = adapt([self.0, self.1, self.2], self.4, white);
+        Self(xyz[0], xyz[1], xyz[2], self.3, white)
+    }
+}
+
+impl Display for XYZ {
+    /// Always uses the CSS Color 4 `color(xyz-d65 x y z)` syntax, or `xyz-d50` for D50
+    ///
+    /// Values are around `0` to `1`, so they get 3 more decimals than the requested precision.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let space = match self.4 {
+            WhitePoint::D50 => "xyz-d50",
+            WhitePoint::D65 => "xyz-d65",
+        };
+        let channels = [
+            space.to_string(),
+            format_number(self.0, precision + 3),
+            format_number(self.1, precision + 3),
+            format_number(self.2, precision + 3),
+        ];
+        write_modern_function(f, "color", &channels, self.3)
+    }
+}
+
+impl PartialEq for XYZ {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+            && self.4 == other.4
+    }
+}
+
+impl TryFrom<&str> for XYZ {
+    type Error = ParseError;
+
+    /// Parses `color(xyz-d65 x y z[ / alpha])`, `xyz-d50` or plain `xyz`, which means D65
+    ///
+    /// As in CSS, `100%` is `1`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_color_function(
+            value.trim(),
+            ColorFormat::Xyz,
+            &["xyz", "xyz-d50", "xyz-d65"],
+        )
+        .map_err(|error| error.shifted(offset))?;
+        let white = match components[0].text.eq_ignore_ascii_case("xyz-d50") {
+            true => WhitePoint::D50,
+            false => WhitePoint::D65,
+        };
+
+        let x = parse_axis(&components[1], ColorFormat::Xyz, "x", 1.0);
+        let y = parse_axis(&components[2], ColorFormat::Xyz, "y", 1.0);
+        let z = parse_axis(&components[3], ColorFormat::Xyz, "z", 1.0);
+        let alpha = parse_alpha(&components, 4, ColorFormat::Xyz);
+
+        match (x, y, z, alpha) {
+            (Ok(x), Ok(y), Ok(z), Ok(alpha)) => Ok(Self(x, y, z, alpha, white)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for XYZ {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Color> for XYZ {
+    fn from(color: Color) -> Self {
+        XYZ::from(LinearRgb::from(color))
+    }
+}
+
+impl From<XYZ> for Color {
+    fn from(color: XYZ) -> Self {
+        Color::from(LinearRgb::from(color))
+    }
+}
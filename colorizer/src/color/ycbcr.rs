@@ -0,0 +1,229 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, LumaStandard, ParseError, RgbSpace, SignalRange, WideRgb, XYZ,
+        YCbCr,
+        cie::Vector,
+        format::{format_number, write_modern_function},
+        parser::{parse_alpha, parse_channel, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+impl LumaStandard {
+    pub const ALL: [LumaStandard; 3] = [
+        LumaStandard::Bt601,
+        LumaStandard::Bt709,
+        LumaStandard::Bt2020,
+    ];
+
+    /// Red and blue luma coefficients, `Kr` and `Kb`
+    pub fn coefficients(&self) -> (f64, f64) {
+        match self {
+            LumaStandard::Bt601 => (0.299, 0.114),
+            LumaStandard::Bt709 => (0.2126, 0.0722),
+            LumaStandard::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+
+    /// Identifier of the standard inside of `ycbcr-` notations
+    pub fn name(&self) -> &'static str {
+        match self {
+            LumaStandard::Bt601 => "bt601",
+            LumaStandard::Bt709 => "bt709",
+            LumaStandard::Bt2020 => "bt2020",
+        }
+    }
+
+    /// Gamma encoded R'G'B' the standard applies its matrix to
+    ///
+    /// BT.601 and BT.709 share the sRGB primaries, BT.2020 signals are Rec. 2020 encoded.
+    fn encode(&self, color: Color) -> Vector {
+        match self {
+            LumaStandard::Bt2020 => {
+                let rgb = WideRgb::from_xyz(XYZ::from(color), RgbSpace::Rec2020);
+                [rgb.0, rgb.1, rgb.2]
+            }
+            _ => [color.0, color.1, color.2],
+        }
+    }
+
+    fn decode(&self, rgb: Vector, alpha: Alpha) -> Color {
+        match self {
+            LumaStandard::Bt2020 => Color::from(
+                WideRgb::new(RgbSpace::Rec2020, rgb[0], rgb[1], rgb[2]).with_alpha(alpha),
+            ),
+            _ => Color(rgb[0], rgb[1], rgb[2], alpha),
+        }
+    }
+}
+
+impl SignalRange {
+    /// 8 bit code value of a luma going from `0.0` to `1.0`
+    pub fn luma_code(&self, luma: f64) -> f64 {
+        match self {
+            SignalRange::Full => luma * 255.0,
+            SignalRange::Limited => 16.0 + luma * 219.0,
+        }
+    }
+
+    /// 8 bit code value of a chroma difference going from `-0.5` to `0.5`
+    pub fn chroma_code(&self, chroma: f64) -> f64 {
+        match self {
+            SignalRange::Full => 128.0 + chroma * 255.0,
+            SignalRange::Limited => 128.0 + chroma * 224.0,
+        }
+    }
+
+    fn luma(&self, code: f64) -> f64 {
+        match self {
+            SignalRange::Full => code / 255.0,
+            SignalRange::Limited => (code - 16.0) / 219.0,
+        }
+    }
+
+    fn chroma(&self, code: f64) -> f64 {
+        match self {
+            SignalRange::Full => (code - 128.0) / 255.0,
+            SignalRange::Limited => (code - 128.0) / 224.0,
+        }
+    }
+}
+
+impl YCbCr {
+    /// Creates a color from its 8 bit code values, such as `YCbCr(235, 128, 128)` for limited
+    /// range white
+    pub fn new(
+        standard: LumaStandard,
+        range: SignalRange,
+        y: impl Into<f64>,
+        cb: impl Into<f64>,
+        cr: impl Into<f64>,
+    ) -> Self {
+        Self(
+            range.luma(y.into()),
+            range.chroma(cb.into()),
+            range.chroma(cr.into()),
+            1.0,
+            standard,
+            range,
+        )
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Encodes `color` as `standard` describes, with code values in `range`
+    pub fn from_color(color: Color, standard: LumaStandard, range: SignalRange) -> Self {
+        let alpha = color.3;
+        let (kr, kb) = standard.coefficients();
+        let [r, g, b] = standard.encode(color);
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+        let cb = (b - y) / (2.0 * (1.0 - kb));
+        let cr = (r - y) / (2.0 * (1.0 - kr));
+        Self(y, cb, cr, alpha, standard, range)
+    }
+}
+
+impl Display for YCbCr {
+    /// Writes the 8 bit code values as `ycbcr-bt709(y cb cr)`, full range adding a `-full` suffix
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let range = self.5;
+        // Like a real 8 bit signal, codes saturate: full range red has a `Cr` of 255.5
+        let channels = [
+            range.luma_code(self.0),
+            range.chroma_code(self.1),
+            range.chroma_code(self.2),
+        ]
+        .map(|code| format_number(code.clamp(0.0, 255.0), precision));
+        let name = match range {
+            SignalRange::Full => format!("ycbcr-{}-full", self.4.name()),
+            SignalRange::Limited => format!("ycbcr-{}", self.4.name()),
+        };
+        write_modern_function(f, &name, &channels, self.3)
+    }
+}
+
+impl PartialEq for YCbCr {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+            && self.4 == other.4
+            && self.5 == other.5
+    }
+}
+
+impl TryFrom<&str> for YCbCr {
+    type Error = ParseError;
+
+    /// Parses `ycbcr-bt601(y cb cr[ / alpha])`, `ycbcr-bt709()` or `ycbcr-bt2020()` 8 bit code
+    /// values, in limited range unless the name ends with `-full`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let value = value.trim();
+        let mut names: Vec<String> = vec![];
+        for standard in LumaStandard::ALL {
+            names.push(format!("ycbcr-{}", standard.name()));
+            names.push(format!("ycbcr-{}-full", standard.name()));
+        }
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let components = split_function(value, ColorFormat::YCbCr, &names, 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let name = value[..value.find('(').unwrap_or(0)].trim().to_lowercase();
+        let range = match name.ends_with("-full") {
+            true => SignalRange::Full,
+            false => SignalRange::Limited,
+        };
+        let standard = LumaStandard::ALL
+            .into_iter()
+            .find(|standard| name.trim_end_matches("-full").ends_with(standard.name()))
+            .unwrap_or(LumaStandard::Bt709);
+
+        let y = parse_channel(&components[0], ColorFormat::YCbCr, "luma", 255.0);
+        let cb = parse_channel(&components[1], ColorFormat::YCbCr, "blue difference", 255.0);
+        let cr = parse_channel(&components[2], ColorFormat::YCbCr, "red difference", 255.0);
+        let alpha = parse_alpha(&components, 3, ColorFormat::YCbCr);
+
+        match (y, cb, cr, alpha) {
+            (Ok(y), Ok(cb), Ok(cr), Ok(alpha)) => {
+                Ok(Self::new(standard, range, y, cb, cr).with_alpha(alpha))
+            }
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for YCbCr {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Color> for YCbCr {
+    /// Uses limited range BT.709, the encoding of HD video
+    fn from(color: Color) -> Self {
+        YCbCr::from_color(color, LumaStandard::Bt709, SignalRange::Limited)
+    }
+}
+
+impl From<YCbCr> for Color {
+    fn from(color: YCbCr) -> Self {
+        let (kr, kb) = color.4.coefficients();
+        let r = color.0 + 2.0 * (1.0 - kr) * color.2;
+        let b = color.0 + 2.0 * (1.0 - kb) * color.1;
+        let g = (color.0 - kr * r - kb * b) / (1.0 - kr - kb);
+        color.4.decode([r, g, b], color.3)
+    }
+}
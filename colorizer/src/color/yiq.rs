@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, ParseError, YIQ,
+        cie::{Matrix, multiply},
+        format::{format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+/// Gamma encoded RGB to YIQ, as defined for NTSC by the FCC
+const RGB_TO_YIQ: Matrix = [
+    [0.299, 0.587, 0.114],
+    [0.5959, -0.2746, -0.3213],
+    [0.2115, -0.5227, 0.3112],
+];
+const YIQ_TO_RGB: Matrix = [
+    [1.0, 0.9560502263958942, 0.6207549413271235],
+    [1.0, -0.27205234368892417, -0.6472057134551777],
+    [1.0, -1.1067043153243326, 1.7044212836963109],
+];
+
+impl YIQ {
+    pub fn new(y: impl Into<f64>, i: impl Into<f64>, q: impl Into<f64>) -> Self {
+        Self(y.into(), i.into(), q.into(), 1.0)
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Display for YIQ {
+    /// Writes `yiq(y i q)`, with 3 more decimals than the requested precision
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format_number(self.0, precision + 3),
+            format_number(self.1, precision + 3),
+            format_number(self.2, precision + 3),
+        ];
+        write_modern_function(f, "yiq", &channels, self.3)
+    }
+}
+
+impl PartialEq for YIQ {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+    }
+}
+
+impl TryFrom<&str> for YIQ {
+    type Error = ParseError;
+
+    /// Parses `yiq(y i q[ / alpha])`, where `100%` is `1`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_function(value.trim(), ColorFormat::Yiq, &["yiq"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let y = parse_axis(&components[0], ColorFormat::Yiq, "luma", 1.0);
+        let i = parse_axis(&components[1], ColorFormat::Yiq, "in-phase", 1.0);
+        let q = parse_axis(&components[2], ColorFormat::Yiq, "quadrature", 1.0);
+        let alpha = parse_alpha(&components, 3, ColorFormat::Yiq);
+
+        match (y, i, q, alpha) {
+            (Ok(y), Ok(i), Ok(q), Ok(alpha)) => Ok(Self::new(y, i, q).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for YIQ {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Color> for YIQ {
+    /// Applies the NTSC matrix to the gamma encoded sRGB channels
+    fn from(color: Color) -> Self {
+        let yiq = multiply(&RGB_TO_YIQ, [color.0, color.1, color.2]);
+        Self::new(yiq[0], yiq[1], yiq[2]).with_alpha(color.3)
+    }
+}
+
+impl From<YIQ> for Color {
+    fn from(color: YIQ) -> Self {
+        let rgb = multiply(&YIQ_TO_RGB, [color.0, color.1, color.2]);
+        Color(rgb[0], rgb[1], rgb[2], color.3)
+    }
+}
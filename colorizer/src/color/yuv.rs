@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use crate::{
+    color::{
+        Alpha, Color, ColorFormat, LumaStandard, ParseError, YUV,
+        format::{format_number, write_modern_function},
+        parser::{parse_alpha, parse_axis, split_function},
+    },
+    core::ranged::EPSILON,
+};
+
+/// Analog PAL scale factors of the `B' - Y'` and `R' - Y'` differences
+const U_SCALE: f64 = 0.492111;
+const V_SCALE: f64 = 0.877283;
+
+impl YUV {
+    pub fn new(y: impl Into<f64>, u: impl Into<f64>, v: impl Into<f64>) -> Self {
+        Self(y.into(), u.into(), v.into(), 1.0)
+    }
+
+    pub fn with_alpha(mut self, alpha: Alpha) -> Self {
+        self.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Display for YUV {
+    /// Writes `yuv(y u v)`, with 3 more decimals than the requested precision
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let channels = [
+            format_number(self.0, precision + 3),
+            format_number(self.1, precision + 3),
+            format_number(self.2, precision + 3),
+        ];
+        write_modern_function(f, "yuv", &channels, self.3)
+    }
+}
+
+impl PartialEq for YUV {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+            && (self.1 - other.1).abs() < EPSILON
+            && (self.2 - other.2).abs() < EPSILON
+            && (self.3 - other.3).abs() < EPSILON
+    }
+}
+
+impl TryFrom<&str> for YUV {
+    type Error = ParseError;
+
+    /// Parses `yuv(y u v[ / alpha])`, where `100%` is `1`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let offset = value.len() - value.trim_start().len();
+        let components = split_function(value.trim(), ColorFormat::Yuv, &["yuv"], 3..=4)
+            .map_err(|error| error.shifted(offset))?;
+
+        let y = parse_axis(&components[0], ColorFormat::Yuv, "luma", 1.0);
+        let u = parse_axis(&components[1], ColorFormat::Yuv, "u", 1.0);
+        let v = parse_axis(&components[2], ColorFormat::Yuv, "v", 1.0);
+        let alpha = parse_alpha(&components, 3, ColorFormat::Yuv);
+
+        match (y, u, v, alpha) {
+            (Ok(y), Ok(u), Ok(v), Ok(alpha)) => Ok(Self::new(y, u, v).with_alpha(alpha)),
+            (Err(error), _, _, _)
+            | (_, Err(error), _, _)
+            | (_, _, Err(error), _)
+            | (_, _, _, Err(error)) => Err(error.shifted(offset)),
+        }
+    }
+}
+
+impl TryFrom<String> for YUV {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Color> for YUV {
+    /// Uses the BT.601 luma of the gamma encoded sRGB channels
+    fn from(color: Color) -> Self {
+        let (kr, kb) = LumaStandard::Bt601.coefficients();
+        let y = kr * color.0 + (1.0 - kr - kb) * color.1 + kb * color.2;
+        Self::new(y, U_SCALE * (color.2 - y), V_SCALE * (color.0 - y)).with_alpha(color.3)
+    }
+}
+
+impl From<YUV> for Color {
+    fn from(color: YUV) -> Self {
+        let (kr, kb) = LumaStandard::Bt601.coefficients();
+        let r = color.0 + color.2 / V_SCALE;
+        let b = color.0 + color.1 / U_SCALE;
+        let g = (color.0 - kr * r - kb * b) / (1.0 - kr - kb);
+        Color(r, g, b, color.3)
+    }
+}
@@ -5,74 +5,62 @@ use std::{
     ops::{Add, Div, Mul, Sub},
 };
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialEq<BaseNumber>
-    for RangedInt<{ LOW }, { HIGH }>
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialEq<f64>
+    for RangedFloat<{ LOW }, { HIGH }>
 {
-    fn eq(&self, other: &BaseNumber) -> bool {
-        self.0 == *other
-    }
-
-    fn ne(&self, other: &BaseNumber) -> bool {
-        self.0 != *other
+    fn eq(&self, other: &f64) -> bool {
+        (self.0 - *other).abs() < EPSILON
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialOrd<BaseNumber>
-    for RangedInt<{ LOW }, { HIGH }>
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialOrd<f64>
+    for RangedFloat<{ LOW }, { HIGH }>
 {
-    fn partial_cmp(&self, other: &BaseNumber) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
         self.0.partial_cmp(other)
     }
 
-    fn ge(&self, other: &BaseNumber) -> bool {
+    fn ge(&self, other: &f64) -> bool {
         self.0 >= *other
     }
 
-    fn le(&self, other: &BaseNumber) -> bool {
+    fn le(&self, other: &f64) -> bool {
         self.0 <= *other
     }
 
-    fn gt(&self, other: &BaseNumber) -> bool {
+    fn gt(&self, other: &f64) -> bool {
         self.0 > *other
     }
 
-    fn lt(&self, other: &BaseNumber) -> bool {
+    fn lt(&self, other: &f64) -> bool {
         self.0 < *other
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Add<BaseNumber>
-    for RangedInt<{ LOW }, { HIGH }>
-{
-    type Output = BaseNumber;
-    fn add(self, rhs: BaseNumber) -> Self::Output {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Add<f64> for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
+    fn add(self, rhs: f64) -> Self::Output {
         self.0 + rhs
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Sub<BaseNumber>
-    for RangedInt<{ LOW }, { HIGH }>
-{
-    type Output = BaseNumber;
-    fn sub(self, rhs: BaseNumber) -> Self::Output {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Sub<f64> for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
+    fn sub(self, rhs: f64) -> Self::Output {
         self.0 - rhs
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Div<BaseNumber>
-    for RangedInt<{ LOW }, { HIGH }>
-{
-    type Output = BaseNumber;
-    fn div(self, rhs: BaseNumber) -> Self::Output {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Div<f64> for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
+    fn div(self, rhs: f64) -> Self::Output {
         self.0 / rhs
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Mul<BaseNumber>
-    for RangedInt<{ LOW }, { HIGH }>
-{
-    type Output = BaseNumber;
-    fn mul(self, rhs: BaseNumber) -> Self::Output {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Mul<f64> for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
+    fn mul(self, rhs: f64) -> Self::Output {
         self.0 * rhs
     }
 }
@@ -5,30 +5,34 @@ mod self_operations;
 
 pub type BaseNumber = i16;
 
-#[derive(Eq, Clone, Debug)]
-pub struct RangedInt<const LOW: BaseNumber, const HIGH: BaseNumber>(BaseNumber);
+/// Tolerance under which two ranged values are considered equal
+pub const EPSILON: f64 = 1e-9;
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> RangedInt<{ LOW }, { HIGH }> {
+/// Floating point number clamped to `LOW..=HIGH`
+#[derive(Clone, Copy, Debug)]
+pub struct RangedFloat<const LOW: BaseNumber, const HIGH: BaseNumber>(f64);
+
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> RangedFloat<{ LOW }, { HIGH }> {
     pub const LOW: BaseNumber = LOW;
     pub const HIGH: BaseNumber = HIGH;
 
-    pub fn new(number: BaseNumber) -> Self {
-        Self(number.min(Self::HIGH).max(Self::LOW))
+    pub fn new(number: f64) -> Self {
+        Self(number.min(Self::HIGH as f64).max(Self::LOW as f64))
     }
 
-    pub fn to_f32(&self) -> f32 {
-        self.0 as f32
+    pub fn to_f64(&self) -> f64 {
+        self.0
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Display for RangedInt<{ LOW }, { HIGH }> {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Display for RangedFloat<{ LOW }, { HIGH }> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        Display::fmt(&self.0, f)
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> UpperHex for RangedInt<{ LOW }, { HIGH }> {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> UpperHex for RangedFloat<{ LOW }, { HIGH }> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        UpperHex::fmt(&self.0, f)
+        UpperHex::fmt(&(self.0.round() as i64), f)
     }
 }
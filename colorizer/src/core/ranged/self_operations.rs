@@ -5,23 +5,19 @@ use std::{
     ops::{Add, Div, Mul, Sub},
 };
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Into<f32> for RangedInt<{ LOW }, { HIGH }> {
-    fn into(self) -> f32 {
-        self.0 as f32
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> From<RangedFloat<{ LOW }, { HIGH }>> for f64 {
+    fn from(value: RangedFloat<{ LOW }, { HIGH }>) -> Self {
+        value.0
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialEq for RangedInt<{ LOW }, { HIGH }> {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialEq for RangedFloat<{ LOW }, { HIGH }> {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
-    }
-
-    fn ne(&self, other: &Self) -> bool {
-        self.0 != other.0
+        (self.0 - other.0).abs() < EPSILON
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialOrd for RangedInt<{ LOW }, { HIGH }> {
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialOrd for RangedFloat<{ LOW }, { HIGH }> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.0.partial_cmp(&other.0)
     }
@@ -43,29 +39,29 @@ impl<const LOW: BaseNumber, const HIGH: BaseNumber> PartialOrd for RangedInt<{ L
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Add for RangedInt<{ LOW }, { HIGH }> {
-    type Output = BaseNumber;
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Add for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
     fn add(self, rhs: Self) -> Self::Output {
         self.0 + rhs.0
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Sub for RangedInt<{ LOW }, { HIGH }> {
-    type Output = BaseNumber;
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Sub for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
     fn sub(self, rhs: Self) -> Self::Output {
         self.0 - rhs.0
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Div for RangedInt<{ LOW }, { HIGH }> {
-    type Output = BaseNumber;
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Div for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
     fn div(self, rhs: Self) -> Self::Output {
         self.0 / rhs.0
     }
 }
 
-impl<const LOW: BaseNumber, const HIGH: BaseNumber> Mul for RangedInt<{ LOW }, { HIGH }> {
-    type Output = BaseNumber;
+impl<const LOW: BaseNumber, const HIGH: BaseNumber> Mul for RangedFloat<{ LOW }, { HIGH }> {
+    type Output = f64;
     fn mul(self, rhs: Self) -> Self::Output {
         self.0 * rhs.0
     }
@@ -0,0 +1,22 @@
+//! Parsing, conversion and formatting of colors, as done by the `colorizer` command line tool
+//!
+//! [`color::Color`] is the hub every model converts through: parse any CSS or video notation
+//! with [`color::Color::try_parse`], convert with `From`/`Into` to a model such as
+//! [`color::OkLch`], read its channels from its fields, and write it back with `Display` or a
+//! [`color::TargetFormat`].
+//!
+//! ```
+//! use colorizer::color::{Color, HSL, OkLch, RGB};
+//!
+//! let color = Color::try_parse(String::from("#3b82f6")).unwrap();
+//! assert_eq!(format!("{}", HSL::from(color.clone())), "hsl(217, 91%, 60%)");
+//! assert_eq!(format!("{:X}", RGB::from(color.clone())), "#3B82F6");
+//! let oklch = OkLch::from(color);
+//! println!("{}", oklch);
+//! // Channels are public fields, as they appear in the notation
+//! assert!((oklch.0 - 0.623).abs() < 0.001);
+//! ```
+#![allow(clippy::upper_case_acronyms)]
+
+pub mod color;
+pub mod core;
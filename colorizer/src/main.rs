@@ -1,112 +1,608 @@
-use arboard::Clipboard;
+use clap::{CommandFactory, Parser};
+use clap_mangen::Man;
 use colored::Colorize;
-use inquire::Select;
-use inquire::Text;
-use std::env;
-
-use crate::color::Color;
-use crate::color::HSL;
-use crate::color::HSV;
-use crate::color::RGB;
-
-mod color;
-pub mod core;
-
-fn set_clipboard(output: String) {
-    let mut ctx = Clipboard::new().unwrap();
-    let result = ctx.set_text(output.clone());
-    match result {
-        Ok(_) => {
-            Text::new(&format!(
-                "{} Copied {} to clipboard!",
-                "✔ ".truecolor(0, 240, 0),
-                output
-            ))
-            .prompt()
-            .unwrap();
+use std::fs::File;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use colorizer::color::BlackGeneration;
+use colorizer::color::Color;
+use colorizer::color::ColorFormat;
+use colorizer::color::ColorMatch;
+use colorizer::color::Gamut;
+use colorizer::color::GamutMapping;
+use colorizer::color::NamedColor;
+use colorizer::color::OkLab;
+use colorizer::color::ParseError;
+use colorizer::color::RGB;
+use colorizer::color::TargetFormat;
+use colorizer::color::contrast;
+
+use crate::batch::BatchError;
+use crate::cli::{
+    BatchArgs, Cli, Command, ConvertArgs, FormatArgs, MixArgs, PaletteArgs, RewriteArgs, SnapArgs,
+};
+use crate::clipboard::{read_clipboard, set_clipboard};
+use crate::report::ColorReport;
+
+mod batch;
+mod cli;
+mod clipboard;
+mod prompt;
+mod report;
+mod rewrite;
+mod scan;
+mod snap;
+
+/// Difference from the closest match under which a color outside of a gamut is not warned
+/// about, in deltaE OK units, a tenth of a just noticeable difference
+const UNNOTICEABLE_DIFFERENCE: f64 = 0.002;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        None => run_convert(None, cli.convert),
+        Some(Command::Convert { color, args }) => run_convert(color, args),
+        Some(Command::Inspect {
+            color,
+            json,
+            format,
+        }) => run_inspect(&color, json, &format),
+        Some(Command::Contrast {
+            foreground,
+            background,
+        }) => run_contrast(&foreground, &background),
+        Some(Command::Mix(args)) => run_mix(args),
+        Some(Command::Palette(args)) => run_palette(args),
+        Some(Command::Batch(args)) => run_batch(args),
+        Some(Command::Scan { json, named, paths }) => run_scan(json, named, &paths),
+        Some(Command::Rewrite(args)) => run_rewrite(args),
+        Some(Command::Snap(args)) => run_snap(args),
+        Some(Command::Completions { shell }) => {
+            clap_complete::generate(shell, &mut Cli::command(), "colorizer", &mut io::stdout());
+            ExitCode::SUCCESS
+        }
+        Some(Command::Man) => match Man::new(Cli::command()).render(&mut io::stdout()) {
+            Ok(_) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{} Error on write: {}", "✘".truecolor(240, 0, 0), error);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// Converts `color`, or the color read from `--input`, the clipboard, stdin or a prompt, and
+/// copies or prints it in the `--to` format or the one chosen from a list
+fn run_convert(color: Option<String>, args: ConvertArgs) -> ExitCode {
+    let FormatArgs {
+        precision,
+        gamut_mapping: mapping,
+        black_generation: generation,
+    } = args.format;
+    // JSON is for scripts, nobody is there to answer a prompt
+    let print = args.print || args.json;
+    let ask = prompt::ENABLED && !print;
+
+    let mut input: String = String::new();
+    if let Some(color) = color.or(args.input) {
+        input = color;
+    } else if args.clipboard {
+        match read_clipboard(args.clipboard_backend) {
+            Ok(data) => input = data,
+            Err(reason) => {
+                eprintln!("{} {}", "✘".truecolor(240, 0, 0), reason);
+                return ExitCode::FAILURE;
+            }
         }
-        Err(_) => {
-            println!("{} Error on copy to clipboard!", "✔ ".truecolor(240, 0, 0),);
+    } else if !ask {
+        if let Err(error) = io::stdin().read_to_string(&mut input) {
+            eprintln!(
+                "{} Error on stdin read: {}",
+                "✘".truecolor(240, 0, 0),
+                error
+            );
+            return ExitCode::FAILURE;
+        }
+    } else {
+        let input_result = prompt::ask_text(
+            "Input color [[#|0x]<hex>, <name>, rgb[a](<r>,<g>,<b>[,<a>]), hsl[a](<h>, <s>, <l>[, <a>]), hsv[a](<h>,<s>,<v>[,<a>]), hwb(<h> <w> <b>), cmyk(<c>, <m>, <y>, <k>), lab(<l> <a> <b>), lch(<l> <c> <h>), oklab(<l> <a> <b>), oklch(<l> <c> <h>), color(<space> <c1> <c2> <c3>), ycbcr-bt709[-full](<y> <cb> <cr>), yuv(<y> <u> <v>), yiq(<y> <i> <q>)]",
+        );
+        if let Some(data) = input_result {
+            input = data;
+        }
+    }
+
+    let (text, color, format) = match find_color(&input, ask) {
+        Ok(found) => found,
+        Err(error) => {
+            print_parse_error(&input, &error);
+            return ExitCode::FAILURE;
+        }
+    };
+    if args.json {
+        let report = ColorReport::new(&text, &color, format, precision, mapping, generation);
+        println!("{}", report.to_json());
+        return ExitCode::SUCCESS;
+    }
+
+    let options = match args.to {
+        Some(format) => vec![(
+            format.format(&color, precision, mapping, generation),
+            format_note(&color, format),
+        )],
+        None => list_color_options(color.clone(), precision, mapping, generation),
+    };
+    if let [(option, note)] = options.as_slice() {
+        if let Some(note) = note {
+            eprintln!("{} {}", "⚠".truecolor(240, 200, 0), note);
+        }
+        match print {
+            true => println!("{}", option),
+            false => set_clipboard(option, args.clipboard_backend),
+        }
+        return ExitCode::SUCCESS;
+    }
+    if !ask {
+        // Values stay one per line on stdout, the notes telling which ones are approximate go
+        // to stderr
+        for (option, note) in options {
+            if let Some(note) = note {
+                eprintln!("{} {}: {}", "⚠".truecolor(240, 200, 0), option, note);
+            }
+            println!("{}", option);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let (options, notes): (Vec<String>, Vec<Option<String>>) = options.into_iter().unzip();
+    let rgb_color: RGB = color.clone().into();
+    let rgb_tuple = rgb_color.to_u8_tuple();
+    let opacity = if color.alpha() < 1.0 {
+        format!(" at {}% opacity", (color.alpha() * 100.0).round())
+    } else {
+        String::new()
+    };
+    let selected_format = prompt::ask_select(
+        &format!(
+            "Encodings of color {}{}",
+            "⬤ ".truecolor(rgb_tuple.0, rgb_tuple.1, rgb_tuple.2),
+            opacity
+        ),
+        options.clone(),
+    );
+
+    match selected_format {
+        Some(index) => {
+            if let Some(note) = &notes[index] {
+                println!("{} {}", "⚠".truecolor(240, 200, 0), note);
+            }
+            set_clipboard(&options[index], args.clipboard_backend);
+            ExitCode::SUCCESS
+        }
+        None => {
+            prompt::acknowledge("Error on input read");
+            ExitCode::FAILURE
         }
     }
 }
 
-fn read_clipboard() -> Result<String, ()> {
-    let mut ctx = Clipboard::new().unwrap();
-    let value = ctx.get_text();
-    match value {
-        Ok(clipboard) => return Ok(clipboard),
-        Err(_) => return Err(()),
+/// Prints the color of `input` in every format, one per line after the format name, or its
+/// report as JSON
+fn run_inspect(input: &str, json: bool, format: &FormatArgs) -> ExitCode {
+    let (text, color, detected) = match find_color(input, false) {
+        Ok(found) => found,
+        Err(error) => {
+            print_parse_error(input, &error);
+            return ExitCode::FAILURE;
+        }
+    };
+    if json {
+        let report = ColorReport::new(
+            &text,
+            &color,
+            detected,
+            format.precision,
+            format.gamut_mapping,
+            format.black_generation,
+        );
+        println!("{}", report.to_json());
+        return ExitCode::SUCCESS;
+    }
+
+    match write_formats(&mut io::stdout().lock(), &color, format) {
+        Ok(_) => ExitCode::SUCCESS,
+        // Output cut short by a pipe, as with `| head -1`, is not a failure
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{} Error on write: {}", "✘".truecolor(240, 0, 0), error);
+            ExitCode::FAILURE
+        }
     }
 }
 
-/**
- * Params
- * --clipboard / -c : read from clipboard first
- * --input <String> / -i <String> : dont read from clipboard nor input, simply try parsing the input
- */
-fn main() {
-    let args: Vec<String> = env::args().collect();
+/// Writes `color` in every format, one per line after the format name
+fn write_formats(out: &mut impl Write, color: &Color, format: &FormatArgs) -> io::Result<()> {
+    let width = TargetFormat::all()
+        .iter()
+        .map(|target| target.name().len())
+        .max()
+        .unwrap_or(0);
+    let hex = format.format(color, TargetFormat::Hex);
+    for target in TargetFormat::all() {
+        let value = format.format(color, target);
+        if target == TargetFormat::ShortHex && value.len() >= hex.len() {
+            continue;
+        }
+        match format_note(color, target) {
+            Some(note) => writeln!(
+                out,
+                "{:<width$}  {}  {} {}",
+                target.name(),
+                value,
+                "⚠".truecolor(240, 200, 0),
+                note
+            )?,
+            None => writeln!(out, "{:<width$}  {}", target.name(), value)?,
+        }
+    }
+    Ok(())
+}
 
-    let mut input: String = String::new();
-    if args.len() == 2 && (args[1] == "--clipboard" || args[1] == "-c") {
-        let clipboard_result = read_clipboard();
-        match clipboard_result {
-            Ok(data) => input = data,
-            Err(()) => {} // Manage error
+/// Prints the contrast ratio of `foreground` over `background` and the WCAG 2 levels it meets
+fn run_contrast(foreground: &Color, background: &Color) -> ExitCode {
+    let ratio = foreground.contrast_ratio(background);
+    println!("Contrast ratio {:.2}:1", ratio);
+    let levels = [
+        ("AA normal text", contrast::WCAG_AA),
+        ("AA large text", contrast::WCAG_AA_LARGE),
+        ("AAA normal text", contrast::WCAG_AAA),
+        ("AAA large text", contrast::WCAG_AAA_LARGE),
+    ];
+    for (level, minimum) in levels {
+        let verdict = match ratio >= minimum {
+            true => "✔ pass".truecolor(0, 240, 0),
+            false => "✘ fail".truecolor(240, 0, 0),
+        };
+        println!("{:<15}  {}  (at least {}:1)", level, verdict, minimum);
+    }
+    ExitCode::SUCCESS
+}
+
+/// Prints the mix of the two colors in the `--to` format
+fn run_mix(args: MixArgs) -> ExitCode {
+    let mixed = args.color.mix(&args.other, args.amount, args.space);
+    println!("{}", args.format.format(&mixed, args.to));
+    ExitCode::SUCCESS
+}
+
+/// Prints the colors of the harmony, or the shades, of the color in the `--to` format
+fn run_palette(args: PaletteArgs) -> ExitCode {
+    let colors = match args.shades {
+        Some(count) => args.color.shades(count),
+        None => args.color.harmony(args.harmony),
+    };
+    for color in colors {
+        println!("{}", args.format.format(&color, args.to));
+    }
+    ExitCode::SUCCESS
+}
+
+/// Converts the lines of the file, or of stdin, to stdout, reporting the lines without a color on
+/// stderr
+fn run_batch(args: BatchArgs) -> ExitCode {
+    let reader: Box<dyn BufRead> = match &args.file {
+        Some(file) if file.as_os_str() != "-" => match File::open(file) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(error) => {
+                eprintln!(
+                    "{} Error on read of {}: {}",
+                    "✘".truecolor(240, 0, 0),
+                    file.display(),
+                    error
+                );
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => Box::new(io::stdin().lock()),
+    };
+    let mut writer = BufWriter::new(io::stdout().lock());
+    let result = batch::convert_batch(reader, &mut writer, args.on_error, |color| {
+        args.format.format(color, args.to)
+    });
+    // Whatever was converted before a failure is still written
+    let flushed = writer.flush();
+
+    match (result, flushed) {
+        (Ok(summary), Ok(_)) => {
+            if summary.skipped > 0 {
+                eprintln!(
+                    "{} Skipped {} lines without a color",
+                    "⚠".truecolor(240, 200, 0),
+                    summary.skipped
+                );
+            }
+            ExitCode::SUCCESS
         }
-    } else if args.len() == 3 && (args[1] == "--input" || args[1] == "-i") {
-        input = args[2].clone();
-    } else {
-        let input_result = Text::new(
-            "Input color [#<hex>, rgb(<r>,<g>,<b>), hsl(<h>, <s>, <l>), hsv(<h>,<s>,<v>)]",
-        )
-        .prompt();
-        match input_result {
-            Ok(data) => input = data,
-            Err(_) => {} // Manage error
-        }
-    }
-
-    let parsed_color = Color::try_parse(input);
-    match parsed_color {
-        Ok(color) => {
-            let options = list_color_options(color.clone());
-            let rgb_color: RGB = color.clone().into();
-            let rgb_tuple = rgb_color.clone().to_u8_tuple();
-            let selected_format = Select::new(
-                &format!(
-                    "Encodings of color {}",
-                    "⬤ ".truecolor(rgb_tuple.0, rgb_tuple.1, rgb_tuple.2)
-                ),
-                options,
-            )
-            .prompt();
-
-            match selected_format {
-                Ok(format) => {
-                    set_clipboard(format.clone());
-                }
-                Err(_) => {
-                    let _ = Text::new("Error on input read").prompt();
-                }
+        (Err(BatchError::Parse { line, text, error }), _) => {
+            eprintln!("{} Error on line {}", "✘".truecolor(240, 0, 0), line);
+            print_parse_error(&text, &error);
+            ExitCode::FAILURE
+        }
+        (Err(BatchError::Io(error)), _) | (_, Err(error)) => {
+            eprintln!("{} Error on batch: {}", "✘".truecolor(240, 0, 0), error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Scans `paths`, printing a table or JSON with `--json`
+fn run_scan(json: bool, named: bool, paths: &[PathBuf]) -> ExitCode {
+    match scan::scan_paths(paths, named) {
+        Ok(entries) if json => println!("{}", scan::format_json(&entries)),
+        Ok(entries) => print!("{}", scan::format_table(&entries)),
+        Err(error) => {
+            eprintln!("{} Error on scan: {}", "✘".truecolor(240, 0, 0), error);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Converts the color literals of the paths into the `--to` format, writing the files back
+/// unless `--dry-run` (print a unified diff) or `--check` (fail when a file would change)
+///
+/// Literals the format can only approximate, such as colors without a CSS name, are left as they
+/// are and listed on stderr.
+fn run_rewrite(args: RewriteArgs) -> ExitCode {
+    let rule = rewrite::RewriteRule {
+        to: args.to,
+        from: args.from,
+        precision: args.format.precision,
+        mapping: args.format.gamut_mapping,
+        generation: args.format.black_generation,
+    };
+    let (rewrites, inexact) = match rewrite::rewrite_paths(&args.paths, &rule) {
+        Ok(rewritten) => rewritten,
+        Err(error) => {
+            eprintln!("{} Error on rewrite: {}", "✘".truecolor(240, 0, 0), error);
+            return ExitCode::FAILURE;
+        }
+    };
+    for found in &inexact {
+        eprintln!(
+            "{} {}:{}:{}  {} has no exact {} form, left as is",
+            "⚠".truecolor(240, 200, 0),
+            found.file.display(),
+            found.line,
+            found.column,
+            found.text,
+            rule.to.name()
+        );
+    }
+    for rewrite in &rewrites {
+        if args.dry_run {
+            print!("{}", rewrite.diff());
+        } else if args.check {
+            println!(
+                "{}: {} colors to rewrite",
+                rewrite.file.display(),
+                rewrite.count
+            );
+        } else if let Err(error) = rewrite.write() {
+            eprintln!(
+                "{} Error on write of {}: {}",
+                "✘".truecolor(240, 0, 0),
+                rewrite.file.display(),
+                error
+            );
+            return ExitCode::FAILURE;
+        } else {
+            println!(
+                "Rewrote {} colors in {}",
+                rewrite.count,
+                rewrite.file.display()
+            );
+        }
+    }
+
+    match args.check && !rewrites.is_empty() {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// Lists the literals of the paths drifting from the `--palette` colors, replacing them with
+/// `--write`
+fn run_snap(args: SnapArgs) -> ExitCode {
+    let target = match args.token {
+        true => snap::SnapTarget::Token,
+        false => snap::SnapTarget::Value,
+    };
+    let palette = match std::fs::read_to_string(&args.palette) {
+        Ok(text) => snap::parse_palette(&args.palette, &text),
+        Err(error) => {
+            eprintln!(
+                "{} Error on read of {}: {}",
+                "✘".truecolor(240, 0, 0),
+                args.palette.display(),
+                error
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    let (paths, tolerance) = (&args.paths, args.tolerance);
+
+    if args.write {
+        let rewrites = match snap::snap_paths(paths, &palette, tolerance, target) {
+            Ok(rewrites) => rewrites,
+            Err(error) => {
+                eprintln!("{} Error on snap: {}", "✘".truecolor(240, 0, 0), error);
+                return ExitCode::FAILURE;
+            }
+        };
+        for rewrite in rewrites {
+            if let Err(error) = rewrite.write() {
+                eprintln!(
+                    "{} Error on write of {}: {}",
+                    "✘".truecolor(240, 0, 0),
+                    rewrite.file.display(),
+                    error
+                );
+                return ExitCode::FAILURE;
             }
+            println!(
+                "Snapped {} colors in {}",
+                rewrite.count,
+                rewrite.file.display()
+            );
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    match snap::find_drift_in_paths(paths, &palette, tolerance) {
+        Ok(drifts) => {
+            for drift in &drifts {
+                let found = &drift.occurrence;
+                println!(
+                    "{}:{}:{}  {} → {}  (deltaE OK {:.4})",
+                    found.file.display(),
+                    found.line,
+                    found.column,
+                    found.text,
+                    drift
+                        .entry
+                        .replacement(target, scan::is_alpha_first(&found.file)),
+                    drift.distance
+                );
+            }
+            println!("{} colors off the palette", drifts.len());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{} Error on snap: {}", "✘".truecolor(240, 0, 0), error);
+            ExitCode::FAILURE
         }
-        Err(()) => (),
     }
 }
 
-fn list_color_options(color: Color) -> Vec<String> {
-    let mut options: Vec<String> = vec![];
+/// Asks which of the colors found in `input` to convert, the first one if the prompt fails
+fn choose_color(input: &str, mut found: Vec<ColorMatch>) -> ColorMatch {
+    let options: Vec<String> = found
+        .iter()
+        .map(|found| format!("{}  ({})", &input[found.span.clone()], found.format))
+        .collect();
+    let index = prompt::ask_select("Several colors found, which one?", options).unwrap_or(0);
+    found.remove(index)
+}
 
-    let rgb_color: RGB = color.clone().into();
-    let hsl_color: HSL = color.clone().into();
-    let hsv_color: HSV = color.clone().into();
+/// The color of `input` with the text it was read from and its notation, when known
+///
+/// When `input` holds several colors, `ask` tells whether to ask which one, the first one being
+/// taken otherwise.
+fn find_color(input: &str, ask: bool) -> Result<(String, Color, Option<ColorFormat>), ParseError> {
+    let mut found = Color::find_all(input);
+    let found = match found.len() {
+        0 => {
+            let color = Color::try_parse(input.to_string())?;
+            return Ok((input.trim().to_string(), color, None));
+        }
+        1 => found.remove(0),
+        _ if ask => choose_color(input, found),
+        _ => found.remove(0),
+    };
+    let text = input[found.span.clone()].to_string();
+    Ok((text, found.color, Some(found.format)))
+}
+
+/// Prints the parse error followed by the offending line with the failing span underlined
+fn print_parse_error(input: &str, error: &ParseError) {
+    eprintln!("{} {}", "✘".truecolor(240, 0, 0), error);
 
-    options.push(format!("{:X}", rgb_color));
-    options.push(format!("{}", rgb_color));
-    options.push(format!("{}", hsl_color));
-    options.push(format!("{}", hsv_color));
+    let line_start = input[..error.span.start]
+        .rfind('\n')
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let line_end = input[line_start..]
+        .find('\n')
+        .map(|index| line_start + index)
+        .unwrap_or(input.len());
+    let line = &input[line_start..line_end];
+    if line.trim().is_empty() {
+        return;
+    }
 
-    options
+    let padding = input[line_start..error.span.start].chars().count();
+    let width = input[error.span.start..error.span.end.min(line_end)]
+        .chars()
+        .count()
+        .max(1);
+    eprintln!("  {}", line);
+    eprintln!(
+        "  {}{}",
+        " ".repeat(padding),
+        "^".repeat(width).truecolor(240, 0, 0)
+    );
+}
+
+/// Warns that `gamut` can't represent `color`, when that is the case
+fn gamut_note(color: &Color, gamut: Gamut) -> Option<String> {
+    // Colors a hair outside, such as display-p3 red in prophoto-rgb, match it without any
+    // visible difference
+    let clipped = color.clone().mapped_to_gamut(gamut, GamutMapping::Clip);
+    let distance = OkLab::from(color.clone()).delta_e(&OkLab::from(clipped));
+    match color.in_gamut(gamut) || distance < UNNOTICEABLE_DIFFERENCE {
+        true => None,
+        false => Some(format!(
+            "The color is outside of the {} gamut, this is the closest match",
+            gamut
+        )),
+    }
+}
+
+/// Warns when `format` does not represent `color` exactly
+fn format_note(color: &Color, format: TargetFormat) -> Option<String> {
+    if format != TargetFormat::Named {
+        return format.gamut().and_then(|gamut| gamut_note(color, gamut));
+    }
+    match NamedColor::exact(color) {
+        Some(_) => None,
+        None => {
+            let (name, distance) = NamedColor::nearest(color);
+            Some(format!(
+                "{} is the nearest named color, {:.3} deltaE OK away",
+                name, distance
+            ))
+        }
+    }
+}
+
+/// Encodings of `color` in every format, each with a note when it does not represent `color`
+/// exactly
+///
+/// Colors outside of a limited gamut are brought inside of it with `mapping`.
+fn list_color_options(
+    color: Color,
+    precision: usize,
+    mapping: GamutMapping,
+    generation: BlackGeneration,
+) -> Vec<(String, Option<String>)> {
+    let hex = TargetFormat::Hex.format(&color, precision, mapping, generation);
+    TargetFormat::all()
+        .into_iter()
+        .filter_map(|format| {
+            let option = format.format(&color, precision, mapping, generation);
+            // The short hex form is only worth offering when it is shorter
+            if format == TargetFormat::ShortHex && option.len() >= hex.len() {
+                return None;
+            }
+            Some((option, format_note(&color, format)))
+        })
+        .collect()
 }
@@ -0,0 +1,43 @@
+#[cfg(feature = "interactive")]
+use inquire::{Select, Text};
+
+/// Whether questions can be asked, prompts being behind the `interactive` feature
+pub const ENABLED: bool = cfg!(feature = "interactive");
+
+/// Asks for a line of text, `None` when the prompt fails or prompts are disabled
+#[cfg(feature = "interactive")]
+pub fn ask_text(message: &str) -> Option<String> {
+    Text::new(message).prompt().ok()
+}
+
+#[cfg(not(feature = "interactive"))]
+pub fn ask_text(_message: &str) -> Option<String> {
+    None
+}
+
+/// Asks to choose one of `options`, returning its index, `None` when the prompt fails or prompts
+/// are disabled
+#[cfg(feature = "interactive")]
+pub fn ask_select(message: &str, options: Vec<String>) -> Option<usize> {
+    Select::new(message, options)
+        .raw_prompt()
+        .ok()
+        .map(|selected| selected.index)
+}
+
+#[cfg(not(feature = "interactive"))]
+pub fn ask_select(_message: &str, _options: Vec<String>) -> Option<usize> {
+    None
+}
+
+/// Shows `message` until it is acknowledged with Enter, only printing it when prompts are
+/// disabled
+#[cfg(feature = "interactive")]
+pub fn acknowledge(message: &str) {
+    let _ = Text::new(message).prompt();
+}
+
+#[cfg(not(feature = "interactive"))]
+pub fn acknowledge(message: &str) {
+    println!("{}", message);
+}
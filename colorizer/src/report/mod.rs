@@ -0,0 +1,101 @@
+#[cfg(test)]
+#[path = "./test/report.test.rs"]
+mod test;
+
+use serde::Serialize;
+
+use colorizer::color::{
+    BlackGeneration, Color, ColorFormat, GamutMapping, NamedColor, RGB, TargetFormat,
+};
+
+/// Everything known about a parsed color, for scripts and editor integrations
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColorReport {
+    /// Text the color was read from
+    pub input: String,
+    /// Notation of the input, such as `hex` or `oklch()`, when it was detected
+    pub format: Option<String>,
+    pub hex: String,
+    pub alpha: f64,
+    /// Exact CSS name of the color
+    pub name: Option<String>,
+    pub nearest_name: NearestName,
+    pub contrast: Contrast,
+    /// The color in every format, in the order they are offered to the user
+    pub encodings: Vec<Encoding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NearestName {
+    pub name: String,
+    /// deltaE OK difference with the color
+    pub distance: f64,
+}
+
+/// WCAG 2 contrast ratios of the color as text over white and black
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Contrast {
+    pub white: f64,
+    pub black: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Encoding {
+    /// Name of the format, as given to `--to`
+    pub format: String,
+    pub value: String,
+    /// Unrounded numbers of the value, absent for names and hex
+    pub components: Option<Vec<f64>>,
+    /// Whether the color fits in the gamut of the format, absent for the unbounded ones
+    pub in_gamut: Option<bool>,
+}
+
+impl ColorReport {
+    /// Describes `color`, read from `input` in the `format` notation when it is known
+    ///
+    /// Encodings are written with `precision` decimals, colors outside of a limited gamut being
+    /// brought inside of it with `mapping`.
+    pub fn new(
+        input: &str,
+        color: &Color,
+        format: Option<ColorFormat>,
+        precision: usize,
+        mapping: GamutMapping,
+        generation: BlackGeneration,
+    ) -> Self {
+        let white = Color::from(RGB::new(255, 255, 255));
+        let black = Color::from(RGB::new(0, 0, 0));
+        let (nearest, distance) = NamedColor::nearest(color);
+        let encodings = TargetFormat::all()
+            .into_iter()
+            .map(|target| Encoding {
+                format: target.name(),
+                value: target.format(color, precision, mapping, generation),
+                components: target.components(color, mapping, generation),
+                in_gamut: target.gamut().map(|gamut| color.in_gamut(gamut)),
+            })
+            .collect();
+
+        ColorReport {
+            input: input.to_string(),
+            format: format.map(|format| format.to_string()),
+            hex: TargetFormat::Hex.format(color, precision, mapping, generation),
+            alpha: color.alpha(),
+            name: NamedColor::exact(color).map(|name| name.to_string()),
+            nearest_name: NearestName {
+                name: nearest.to_string(),
+                distance,
+            },
+            contrast: Contrast {
+                white: color.contrast_ratio(&white),
+                black: color.contrast_ratio(&black),
+            },
+            encodings,
+        }
+    }
+
+    /// Pretty printed JSON object of the report
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+pub mod tests {
+    use colorizer::color::{BlackGeneration, Color, ColorFormat, GamutMapping};
+
+    use crate::report::ColorReport;
+
+    fn report(input: &str, format: Option<ColorFormat>) -> ColorReport {
+        let color = Color::try_parse(input.to_string()).unwrap();
+        ColorReport::new(
+            input,
+            &color,
+            format,
+            0,
+            GamutMapping::Perceptual,
+            BlackGeneration::Naive,
+        )
+    }
+
+    #[test]
+    fn test_report() {
+        let report = report("rgb(255 0 0 / 50%)", Some(ColorFormat::Rgb));
+        assert_eq!(report.format.as_deref(), Some("rgb()"));
+        assert_eq!(report.hex, "#FF000080");
+        assert_eq!(report.alpha, 0.5);
+        assert_eq!(report.name, None);
+        assert_eq!(report.nearest_name.name, "red");
+
+        let rgb = &report.encodings[3];
+        assert_eq!(rgb.format, "rgb");
+        assert_eq!(rgb.value, "rgba(255, 0, 0, 0.5)");
+        assert_eq!(rgb.components, Some(vec![255.0, 0.0, 0.0]));
+        assert_eq!(rgb.in_gamut, Some(true));
+        assert_eq!(report.encodings[1].components, None);
+        let oklab = report
+            .encodings
+            .iter()
+            .find(|encoding| encoding.format == "oklab")
+            .unwrap();
+        assert_eq!(oklab.in_gamut, None);
+    }
+
+    #[test]
+    fn test_report_contrast() {
+        let report = report("white", Some(ColorFormat::Named));
+        assert_eq!(report.name.as_deref(), Some("white"));
+        assert!((report.contrast.white - 1.0).abs() < 1e-9);
+        assert!((report.contrast.black - 21.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_report_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&report("#3b82f6", Some(ColorFormat::Hex)).to_json()).unwrap();
+        assert_eq!(json["input"], "#3b82f6");
+        assert_eq!(json["format"], "hex");
+        assert_eq!(json["hex"], "#3B82F6");
+        assert_eq!(json["name"], serde_json::Value::Null);
+        assert!(json["contrast"]["black"].as_f64().unwrap() > 5.0);
+        let formats: Vec<&str> = json["encodings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|encoding| encoding["format"].as_str().unwrap())
+            .collect();
+        assert!(formats.contains(&"oklch") && formats.contains(&"display-p3"));
+    }
+}
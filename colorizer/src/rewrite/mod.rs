@@ -0,0 +1,169 @@
+#[cfg(test)]
+#[path = "./test/rewrite.test.rs"]
+mod test;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use similar::TextDiff;
+
+use colorizer::color::{
+    BlackGeneration, Color, ColorFormat, ColorMatch, GamutMapping, NamedColor, TargetFormat,
+};
+
+use crate::scan::{
+    self, Occurrence, find_colors, in_selector, is_alpha_first, position, read_source, source_files,
+};
+
+/// Notations left alone unless asked for, as their words and numbers are too often something
+/// else in source code: `red` in a comment, `Color.Red`, or a `0xFF00FF` bit mask
+const SKIPPED_BY_DEFAULT: [ColorFormat; 2] = [ColorFormat::Named, ColorFormat::HexLiteral];
+
+/// Which color literals get rewritten, and how
+#[derive(Debug, Clone)]
+pub struct RewriteRule {
+    pub to: TargetFormat,
+    /// Names of the notations to rewrite, such as `hex`, `hsl` or `named`; when empty, every
+    /// notation except CSS names and `0x` literals
+    pub from: Vec<String>,
+    pub precision: usize,
+    pub mapping: GamutMapping,
+    pub generation: BlackGeneration,
+}
+
+/// Source file whose colors were rewritten, not written back yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileRewrite {
+    pub file: PathBuf,
+    pub original: String,
+    pub rewritten: String,
+    /// Number of literals that changed
+    pub count: usize,
+}
+
+impl RewriteRule {
+    /// Whether literals written in `format` are rewritten
+    pub fn applies_to(&self, format: ColorFormat) -> bool {
+        match self.from.is_empty() {
+            true => !SKIPPED_BY_DEFAULT.contains(&format),
+            false => self
+                .from
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&format_name(format))),
+        }
+    }
+
+    /// Whether `color` can be written in the `to` format without changing it, which CSS names
+    /// only do for the colors that have one
+    pub fn writes_exactly(&self, color: &Color) -> bool {
+        self.to != TargetFormat::Named || NamedColor::exact(color).is_some()
+    }
+}
+
+impl FileRewrite {
+    /// Unified diff from the original file to the rewritten one
+    pub fn diff(&self) -> String {
+        let path = self.file.display().to_string();
+        TextDiff::from_lines(&self.original, &self.rewritten)
+            .unified_diff()
+            .header(&path, &path)
+            .to_string()
+    }
+
+    pub fn write(&self) -> io::Result<()> {
+        fs::write(&self.file, &self.rewritten)
+    }
+}
+
+/// Rewrites every color literal of `text` matching `rule`, keeping everything around them
+///
+/// Hex colors of Android sources, as told by the extension of `file`, are read and written
+/// alpha first. Literals the rule cannot write exactly are left as they are, see
+/// [`inexact_literals`]. Returns the new text along with how many literals changed.
+pub fn rewrite_text(file: &Path, text: &str, rule: &RewriteRule) -> (String, usize) {
+    let alpha_first = is_alpha_first(file);
+    let mut rewritten = String::with_capacity(text.len());
+    let mut count = 0;
+    let mut end = 0;
+    for found in find_colors(file, text) {
+        if !rewrites(text, &found, rule) {
+            continue;
+        }
+        let original = &text[found.span.clone()];
+        let mut replacement =
+            rule.to
+                .format(&found.color, rule.precision, rule.mapping, rule.generation);
+        if alpha_first && matches!(rule.to, TargetFormat::Hex | TargetFormat::ShortHex) {
+            replacement = scan::alpha_first(&replacement).unwrap_or(replacement);
+        }
+        if replacement != original {
+            count += 1;
+        }
+        rewritten += &text[end..found.span.start];
+        rewritten += &replacement;
+        end = found.span.end;
+    }
+    rewritten += &text[end..];
+    (rewritten, count)
+}
+
+/// Literals of `text` selected by `rule` that it can only write approximately, such as colors
+/// without an exact CSS name, which [`rewrite_text`] leaves alone
+pub fn inexact_literals(file: &Path, text: &str, rule: &RewriteRule) -> Vec<Occurrence> {
+    find_colors(file, text)
+        .into_iter()
+        .filter(|found| {
+            rule.applies_to(found.format)
+                && !in_selector(text, found)
+                && !rule.writes_exactly(&found.color)
+        })
+        .map(|found| {
+            let (line, column) = position(text, found.span.start);
+            Occurrence {
+                file: file.to_path_buf(),
+                line,
+                column,
+                text: text[found.span].to_string(),
+                format: found.format.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Rewrites the files of `paths`, as listed by [`source_files`], returning those that changed
+/// along with the literals left alone as [`inexact_literals`]
+pub fn rewrite_paths(
+    paths: &[PathBuf],
+    rule: &RewriteRule,
+) -> io::Result<(Vec<FileRewrite>, Vec<Occurrence>)> {
+    let mut rewrites = vec![];
+    let mut inexact = vec![];
+    for file in source_files(paths)? {
+        let Some(original) = read_source(&file)? else {
+            continue;
+        };
+        inexact.extend(inexact_literals(&file, &original, rule));
+        let (rewritten, count) = rewrite_text(&file, &original, rule);
+        if count > 0 {
+            rewrites.push(FileRewrite {
+                file,
+                original,
+                rewritten,
+                count,
+            });
+        }
+    }
+    Ok((rewrites, inexact))
+}
+
+/// Whether `found` is rewritten by `rule`
+fn rewrites(text: &str, found: &ColorMatch, rule: &RewriteRule) -> bool {
+    rule.applies_to(found.format) && !in_selector(text, found) && rule.writes_exactly(&found.color)
+}
+
+/// Name a notation is selected by: `hex`, `hex-literal`, `rgb`, `color(display-p3)`...
+fn format_name(format: ColorFormat) -> String {
+    format.to_string().trim_end_matches("()").replace(' ', "-")
+}
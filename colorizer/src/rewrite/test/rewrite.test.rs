@@ -0,0 +1,159 @@
+#[cfg(test)]
+pub mod tests {
+    use std::{fs, path::Path};
+
+    use colorizer::color::{BlackGeneration, ColorFormat, GamutMapping, TargetFormat};
+
+    use crate::rewrite::{RewriteRule, inexact_literals, rewrite_paths, rewrite_text};
+
+    fn rule(to: TargetFormat, from: &[&str]) -> RewriteRule {
+        RewriteRule {
+            to,
+            from: from.iter().map(|name| name.to_string()).collect(),
+            precision: 0,
+            mapping: GamutMapping::Perceptual,
+            generation: BlackGeneration::Naive,
+        }
+    }
+
+    #[test]
+    fn test_rewrite_text() {
+        let text = "a { color: #ff0000; background: hsla(240, 100%, 50%, 0.5); }";
+        assert_eq!(
+            rewrite_text(Path::new("a.css"), text, &rule(TargetFormat::Rgb, &[])),
+            (
+                String::from("a { color: rgb(255, 0, 0); background: rgba(0, 0, 255, 0.5); }"),
+                2
+            )
+        );
+        assert_eq!(
+            rewrite_text(Path::new("a.css"), text, &rule(TargetFormat::Hex, &["hsl"])),
+            (
+                String::from("a { color: #ff0000; background: #0000FF80; }"),
+                1
+            )
+        );
+        assert_eq!(
+            rewrite_text(
+                Path::new("a.css"),
+                "#FF0000 and #00FF00",
+                &rule(TargetFormat::Hex, &[])
+            ),
+            (String::from("#FF0000 and #00FF00"), 0)
+        );
+    }
+
+    #[test]
+    fn test_rewrite_skips_names_and_literals() {
+        let text = "// red border\nval mask = 0xFF00FF\nborder: 1px solid #f00";
+        let default = rule(TargetFormat::Hex, &[]);
+        assert!(!default.applies_to(ColorFormat::Named));
+        assert_eq!(
+            rewrite_text(Path::new("a.css"), text, &default).0,
+            "// red border\nval mask = 0xFF00FF\nborder: 1px solid #FF0000"
+        );
+        assert_eq!(
+            rewrite_text(
+                Path::new("a.css"),
+                text,
+                &rule(TargetFormat::Hex, &["named", "hex-literal"])
+            )
+            .0,
+            "// #FF0000 border\nval mask = #FF00FF\nborder: 1px solid #f00"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_skips_id_selectors() {
+        let text = "#fab-button {\n  color: #fab;\n}\n#add, #bad {\n  border: 1px solid #bad;\n}\n";
+        assert_eq!(
+            rewrite_text(Path::new("a.css"), text, &rule(TargetFormat::Rgb, &[])),
+            (
+                String::from(
+                    "#fab-button {\n  color: rgb(255, 170, 187);\n}\n#add, #bad {\n  border: 1px solid rgb(187, 170, 221);\n}\n"
+                ),
+                2
+            )
+        );
+    }
+
+    #[test]
+    fn test_rewrite_android_alpha_first() {
+        let text = "<color name=\"scrim\">#80FF0000</color>\n<color name=\"tint\">#8f00</color>";
+        let file = Path::new("res/values/colors.xml");
+        assert_eq!(
+            rewrite_text(file, text, &rule(TargetFormat::Rgb, &[])).0,
+            "<color name=\"scrim\">rgba(255, 0, 0, 0.502)</color>\n<color name=\"tint\">rgba(255, 0, 0, 0.533)</color>"
+        );
+        // Hex stays alpha first
+        assert_eq!(
+            rewrite_text(file, text, &rule(TargetFormat::Hex, &[])),
+            (
+                String::from(
+                    "<color name=\"scrim\">#80FF0000</color>\n<color name=\"tint\">#88FF0000</color>"
+                ),
+                1
+            )
+        );
+        let kotlin = "val scrim = \"#80ff0000\"";
+        assert_eq!(
+            rewrite_text(Path::new("Theme.kt"), kotlin, &rule(TargetFormat::Hsl, &[])).0,
+            "val scrim = \"hsla(0, 100%, 50%, 0.502)\""
+        );
+    }
+
+    #[test]
+    fn test_rewrite_only_exact_names() {
+        let text = "a { color: #ff0000; background: rgba(255, 0, 0, 0.5); border-color: #fe0000; }";
+        let file = Path::new("a.css");
+        let to_named = rule(TargetFormat::Named, &[]);
+        assert_eq!(
+            rewrite_text(file, text, &to_named),
+            (
+                String::from(
+                    "a { color: red; background: rgba(255, 0, 0, 0.5); border-color: #fe0000; }"
+                ),
+                1
+            )
+        );
+        let inexact = inexact_literals(file, text, &to_named);
+        let inexact: Vec<(usize, &str)> = inexact
+            .iter()
+            .map(|found| (found.column, found.text.as_str()))
+            .collect();
+        assert_eq!(inexact, vec![(33, "rgba(255, 0, 0, 0.5)"), (69, "#fe0000")]);
+        assert!(inexact_literals(file, text, &rule(TargetFormat::Hex, &[])).is_empty());
+    }
+
+    #[test]
+    fn test_rewrite_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let style = root.path().join("style.css");
+        fs::write(&style, "a {\n  color: #fff;\n}\n").unwrap();
+        fs::write(root.path().join("plain.css"), "a { display: none; }\n").unwrap();
+
+        let (rewrites, inexact) =
+            rewrite_paths(&[root.path().to_path_buf()], &rule(TargetFormat::Hsl, &[])).unwrap();
+        assert!(inexact.is_empty());
+        assert_eq!(rewrites.len(), 1);
+        assert_eq!(rewrites[0].count, 1);
+        let path = style.display();
+        assert_eq!(
+            rewrites[0].diff(),
+            format!(
+                "--- {path}\n+++ {path}\n@@ -1,3 +1,3 @@\n a {{\n-  color: #fff;\n+  color: hsl(0, 0%, 100%);\n }}\n"
+            )
+        );
+
+        // Nothing is written until asked to
+        assert_eq!(
+            fs::read_to_string(&style).unwrap(),
+            "a {\n  color: #fff;\n}\n"
+        );
+        rewrites[0].write().unwrap();
+        assert_eq!(
+            fs::read_to_string(&style).unwrap(),
+            "a {\n  color: hsl(0, 0%, 100%);\n}\n"
+        );
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(test)]
+#[path = "./test/scan.test.rs"]
+mod test;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+use walkdir::{DirEntry, WalkDir};
+
+use colorizer::color::{Color, ColorFormat, ColorMatch, RGB};
+
+/// Extensions of the files read when walking a directory: stylesheets, frontend sources, mobile
+/// sources and resource files
+pub const EXTENSIONS: [&str; 9] = [
+    "css", "scss", "less", "tsx", "kt", "kts", "swift", "xml", "json",
+];
+/// Extensions of Android sources, whose `#AARRGGBB` and `#ARGB` hex colors put alpha first
+const ALPHA_FIRST_EXTENSIONS: [&str; 3] = ["kt", "kts", "xml"];
+/// Directories never walked into, besides hidden ones
+const SKIPPED_DIRECTORIES: [&str; 3] = ["node_modules", "target", "build"];
+
+/// Color literal found in a source file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Occurrence {
+    pub file: PathBuf,
+    /// Line of the literal, starting at `1`
+    pub line: usize,
+    /// Character of the line where the literal starts, starting at `1`
+    pub column: usize,
+    /// Literal as written in the source
+    pub text: String,
+    /// Notation of the literal, `hex`, `rgb()`, `named`...
+    pub format: String,
+}
+
+/// Every occurrence of one color, identified by its normalized hex
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScanEntry {
+    pub hex: String,
+    pub count: usize,
+    pub occurrences: Vec<Occurrence>,
+}
+
+/// Finds every color literal of `text`, reporting them as being in `file`
+///
+/// CSS names are only reported with `named`, as they are too often plain words of comments and
+/// identifiers.
+pub fn scan_text(file: &Path, text: &str, named: bool) -> Vec<(String, Occurrence)> {
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut read = 0;
+    let mut found = vec![];
+    for color_match in find_colors(file, text) {
+        if in_selector(text, &color_match) || (!named && color_match.format == ColorFormat::Named) {
+            continue;
+        }
+        for (index, _) in text[read..color_match.span.start].match_indices('\n') {
+            line += 1;
+            line_start = read + index + 1;
+        }
+        read = color_match.span.start;
+
+        let occurrence = Occurrence {
+            file: file.to_path_buf(),
+            line,
+            column: text[line_start..color_match.span.start].chars().count() + 1,
+            text: text[color_match.span.clone()].to_string(),
+            format: color_match.format.to_string(),
+        };
+        found.push((normalized_hex(color_match.color), occurrence));
+    }
+    found
+}
+
+/// Every color of `text`, as [`Color::find_all`] finds them, the hex colors of Android sources
+/// being read alpha first
+pub fn find_colors(file: &Path, text: &str) -> Vec<ColorMatch> {
+    let mut found = Color::find_all(text);
+    if is_alpha_first(file) {
+        for color_match in found.iter_mut() {
+            if color_match.format != ColorFormat::Hex {
+                continue;
+            }
+            let written = &text[color_match.span.clone()];
+            if let Some(color) = alpha_last(written).and_then(|hex| Color::try_parse(hex).ok()) {
+                color_match.color = color;
+            }
+        }
+    }
+    found
+}
+
+/// Whether `file` is an Android source, whose hex colors put alpha first
+pub fn is_alpha_first(file: &Path) -> bool {
+    has_extension(file, &ALPHA_FIRST_EXTENSIONS)
+}
+
+/// CSS `#RRGGBBAA` or `#RGBA` hex written as the Android `#AARRGGBB` or `#ARGB`, `None` for
+/// the lengths without alpha
+pub fn alpha_first(hex: &str) -> Option<String> {
+    let digits = hex.strip_prefix('#')?;
+    match digits.len() {
+        4 | 8 => {
+            let (color, alpha) = digits.split_at(digits.len() * 3 / 4);
+            Some(format!("#{}{}", alpha, color))
+        }
+        _ => None,
+    }
+}
+
+/// Whether a hex color is a CSS ID selector, as `#add` in `#add, #bad { ... }`
+///
+/// It is when the rule it is in opens a block right after it, and nothing before it assigns a
+/// value, as `color: ` or `=` do.
+pub fn in_selector(text: &str, found: &ColorMatch) -> bool {
+    if found.format != ColorFormat::Hex {
+        return false;
+    }
+    let start = text[..found.span.start]
+        .rfind([';', '{', '}'])
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let opens_block = text[found.span.end..]
+        .find([';', '{', '}'])
+        .is_some_and(|index| text[found.span.end + index..].starts_with('{'));
+    let before = text[start..found.span.start].trim_end();
+    opens_block && !before.contains('=') && !before.contains(": ") && !before.ends_with(':')
+}
+
+/// Line and character column of the byte `offset` of `text`, both starting at `1`
+pub fn position(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    let line = before.matches('\n').count() + 1;
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// Every given file, and every file with a known extension inside of the given directories
+pub fn source_files(paths: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for path in paths {
+        let walker = WalkDir::new(path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| entry.depth() == 0 || !is_skipped(entry));
+        for entry in walker {
+            let entry = entry.map_err(io::Error::from)?;
+            let explicit = entry.depth() == 0;
+            if entry.file_type().is_file() && (explicit || has_known_extension(entry.path())) {
+                files.push(entry.into_path());
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Reads a source file, `None` when it is not UTF-8 text
+pub fn read_source(file: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(file) {
+        Ok(text) => Ok(Some(text)),
+        Err(error) if error.kind() == io::ErrorKind::InvalidData => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Scans every file of `paths`, as listed by [`source_files`]
+///
+/// Files that are not UTF-8 text are skipped, and CSS names unless `named`.
+pub fn scan_paths(paths: &[PathBuf], named: bool) -> io::Result<Vec<ScanEntry>> {
+    let mut found = vec![];
+    for file in source_files(paths)? {
+        if let Some(text) = read_source(&file)? {
+            found.extend(scan_text(&file, &text, named));
+        }
+    }
+    Ok(group_by_hex(found))
+}
+
+/// Groups the occurrences by color, the most used colors first
+pub fn group_by_hex(found: Vec<(String, Occurrence)>) -> Vec<ScanEntry> {
+    let mut entries: Vec<ScanEntry> = vec![];
+    for (hex, occurrence) in found {
+        match entries.iter_mut().find(|entry| entry.hex == hex) {
+            Some(entry) => {
+                entry.count += 1;
+                entry.occurrences.push(occurrence);
+            }
+            None => entries.push(ScanEntry {
+                hex,
+                count: 1,
+                occurrences: vec![occurrence],
+            }),
+        }
+    }
+    entries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.hex.cmp(&b.hex)));
+    entries
+}
+
+/// Writes one row per occurrence in source order, `file:line:column  hex  literal`, followed by
+/// the number of occurrences of every color
+pub fn format_table(entries: &[ScanEntry]) -> String {
+    let mut rows: Vec<(&Occurrence, &str)> = entries
+        .iter()
+        .flat_map(|entry| {
+            entry
+                .occurrences
+                .iter()
+                .map(|found| (found, entry.hex.as_str()))
+        })
+        .collect();
+    rows.sort_by(|(a, _), (b, _)| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+    let locations: Vec<String> = rows
+        .iter()
+        .map(|(found, _)| format!("{}:{}:{}", found.file.display(), found.line, found.column))
+        .collect();
+    let location_width = locations.iter().map(String::len).max().unwrap_or(0);
+    let hex_width = entries
+        .iter()
+        .map(|entry| entry.hex.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut table = String::new();
+    for ((found, hex), location) in rows.iter().zip(&locations) {
+        table += &format!(
+            "{:<location_width$}  {:<hex_width$}  {}\n",
+            location, hex, found.text
+        );
+    }
+    if !entries.is_empty() {
+        table += "\n";
+    }
+    for entry in entries {
+        table += &format!("{:<hex_width$}  {}\n", entry.hex, entry.count);
+    }
+    let total: usize = entries.iter().map(|entry| entry.count).sum();
+    table += &format!("{} colors, {} distinct\n", total, entries.len());
+    table
+}
+
+/// Writes the entries as a JSON array, for other tools to consume
+pub fn format_json(entries: &[ScanEntry]) -> String {
+    serde_json::to_string_pretty(entries).expect("scan entries always serialize")
+}
+
+/// `#RRGGBB` or `#RRGGBBAA` of the color, brought inside of sRGB
+fn normalized_hex(color: Color) -> String {
+    format!("{:X}", RGB::from(color))
+}
+
+/// Android `#AARRGGBB` or `#ARGB` hex written as the CSS `#RRGGBBAA` or `#RGBA`, `None` for
+/// the lengths without alpha
+pub fn alpha_last(hex: &str) -> Option<String> {
+    let digits = hex.strip_prefix('#')?;
+    match digits.len() {
+        4 | 8 => {
+            let (alpha, color) = digits.split_at(digits.len() / 4);
+            Some(format!("#{}{}", color, alpha))
+        }
+        _ => None,
+    }
+}
+
+fn has_known_extension(path: &Path) -> bool {
+    has_extension(path, &EXTENSIONS)
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            extensions
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(extension))
+        })
+}
+
+fn is_skipped(entry: &DirEntry) -> bool {
+    let name = entry.file_name().to_string_lossy();
+    entry.file_type().is_dir() && (name.starts_with('.') || SKIPPED_DIRECTORIES.contains(&&*name))
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+pub mod tests {
+    use std::{fs, path::Path};
+
+    use crate::scan::{format_json, format_table, group_by_hex, scan_paths, scan_text};
+
+    #[test]
+    fn test_scan_text_positions() {
+        let text = ".button {\n  color: #ff0000;\n  background: rgb(0 0 255) red;\n}";
+        let found = scan_text(Path::new("style.css"), text, true);
+        let positions: Vec<(usize, usize, &str, &str)> = found
+            .iter()
+            .map(|(hex, found)| (found.line, found.column, found.text.as_str(), hex.as_str()))
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                (2, 10, "#ff0000", "#FF0000"),
+                (3, 15, "rgb(0 0 255)", "#0000FF"),
+                (3, 28, "red", "#FF0000"),
+            ]
+        );
+        assert_eq!(found[1].1.format, "rgb()");
+    }
+
+    #[test]
+    fn test_scan_skips_names_by_default() {
+        let text = "/* a red border, tan-colored in print */\nborder: 1px solid red;";
+        assert!(scan_text(Path::new("style.css"), text, false).is_empty());
+        let found = scan_text(Path::new("style.css"), text, true);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[1].1.line, 2);
+    }
+
+    #[test]
+    fn test_scan_android_alpha_first() {
+        let text = "<color name=\"scrim\">#80000000</color>\n<color name=\"tint\">#8f00</color>";
+        let found = scan_text(Path::new("res/values/colors.xml"), text, true);
+        let hexes: Vec<&str> = found.iter().map(|(hex, _)| hex.as_str()).collect();
+        assert_eq!(hexes, ["#00000080", "#FF000088"]);
+        assert_eq!(found[0].1.text, "#80000000");
+
+        let found = scan_text(Path::new("style.css"), "a { color: #80000000 }", true);
+        assert_eq!(found[0].0, "#80000000");
+    }
+
+    #[test]
+    fn test_scan_counts() {
+        let text = "#f00 #00f red #0000ff80 rgb(255, 0, 0)";
+        let entries = group_by_hex(scan_text(Path::new("a.scss"), text, true));
+        let counts: Vec<(&str, usize)> = entries
+            .iter()
+            .map(|entry| (entry.hex.as_str(), entry.count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![("#FF0000", 3), ("#0000FF", 1), ("#0000FF80", 1)]
+        );
+
+        let table = format_table(&entries);
+        assert!(table.starts_with("a.scss:1:1   #FF0000    #f00\n"));
+        assert!(
+            table.ends_with("#FF0000    3\n#0000FF    1\n#0000FF80  1\n5 colors, 3 distinct\n")
+        );
+    }
+
+    #[test]
+    fn test_scan_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let write = |name: &str, text: &str| {
+            let path = root.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        };
+        write("src/theme.kt", "val brand = Color(0xFF3366CC)");
+        write("src/App.tsx", "<div style={{ color: '#3366cc' }} />");
+        write("src/notes.md", "#3366cc is ignored");
+        write("node_modules/lib/index.css", "a { color: #3366cc }");
+        write(".cache/old.css", "a { color: #3366cc }");
+
+        let entries = scan_paths(&[root.path().to_path_buf()], false).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].hex, "#3366CC");
+        let files: Vec<&Path> = entries[0]
+            .occurrences
+            .iter()
+            .map(|found| found.file.strip_prefix(root.path()).unwrap())
+            .collect();
+        assert_eq!(
+            files,
+            vec![Path::new("src/App.tsx"), Path::new("src/theme.kt")]
+        );
+
+        // Files given explicitly are read whatever their extension
+        let notes = root.path().join("src/notes.md");
+        assert_eq!(scan_paths(&[notes], false).unwrap()[0].count, 1);
+    }
+
+    #[test]
+    fn test_scan_json() {
+        let entries = group_by_hex(scan_text(
+            Path::new("colors.json"),
+            "{\"a\": \"#fff\"}",
+            true,
+        ));
+        let json: serde_json::Value = serde_json::from_str(&format_json(&entries)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "hex": "#FFFFFF",
+                "count": 1,
+                "occurrences": [{
+                    "file": "colors.json",
+                    "line": 1,
+                    "column": 8,
+                    "text": "#fff",
+                    "format": "hex",
+                }],
+            }])
+        );
+    }
+}
@@ -0,0 +1,226 @@
+#[cfg(test)]
+#[path = "./test/snap.test.rs"]
+mod test;
+
+use std::{
+    io,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use colorizer::color::{Color, ColorFormat, OkLab, RGB};
+
+use crate::{
+    rewrite::FileRewrite,
+    scan::{
+        self, Occurrence, find_colors, in_selector, is_alpha_first, position, read_source,
+        source_files,
+    },
+};
+
+/// Tolerance used when none is given, the deltaE OK difference under which two colors are
+/// usually indistinguishable
+///
+/// deltaE OK is the distance in OKLab, whose lightness goes from 0 to 1, so its values are 100
+/// times smaller than those of CIE deltaE: this is about a CIE deltaE of 2.
+pub const DEFAULT_TOLERANCE: f64 = 0.02;
+
+/// Color of a palette, with the token it is known by when the palette names it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteEntry {
+    /// `--brand-blue`, `$primary`, `primary`... as written before the color
+    pub token: Option<String>,
+    /// The color as written in the palette, hex colors of Android palettes moved to CSS order
+    pub text: String,
+    pub color: Color,
+}
+
+/// Literal close to a palette color without being it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    pub occurrence: Occurrence,
+    pub entry: PaletteEntry,
+    /// deltaE OK between the literal and the palette color
+    pub distance: f64,
+}
+
+/// What drifting literals are replaced with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapTarget {
+    /// The palette color, written as in the palette
+    Value,
+    /// The token of the palette color, `var(--name)` for CSS custom properties, falling back to
+    /// the value for unnamed colors
+    Token,
+}
+
+impl PaletteEntry {
+    /// Text a drifting literal is replaced with, translucent hex colors being written alpha first
+    /// with `alpha_first`, for Android sources
+    pub fn replacement(&self, target: SnapTarget, alpha_first: bool) -> String {
+        match (target, &self.token) {
+            (SnapTarget::Token, Some(token)) if token.starts_with("--") => {
+                format!("var({})", token)
+            }
+            (SnapTarget::Token, Some(token)) => token.clone(),
+            _ if alpha_first => scan::alpha_first(&self.text).unwrap_or(self.text.clone()),
+            _ => self.text.clone(),
+        }
+    }
+}
+
+/// Reads one palette color per line, the last one of the line, its token being the name
+/// assigned to it
+///
+/// Accepts CSS custom properties (`--brand: #3b82f6;`), SCSS and LESS variables, JSON members
+/// and plain `name = color` or bare color lines. The hex colors of an Android palette, as told
+/// by the extension of `file`, are read alpha first.
+pub fn parse_palette(file: &Path, text: &str) -> Vec<PaletteEntry> {
+    let alpha_first = is_alpha_first(file);
+    text.lines()
+        .filter_map(|line| {
+            let found = find_colors(file, line).into_iter().next_back()?;
+            let token = line[..found.span.start]
+                .trim_end_matches(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+                .trim_end_matches([':', '='])
+                .trim_end_matches(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+                .rsplit(|c: char| !(c.is_alphanumeric() || "-_$@.".contains(c)))
+                .next()
+                .filter(|token| !token.is_empty())
+                .map(String::from);
+            let mut text = line[found.span].to_string();
+            if alpha_first && found.format == ColorFormat::Hex {
+                text = scan::alpha_last(&text).unwrap_or(text);
+            }
+            Some(PaletteEntry {
+                token,
+                text,
+                color: found.color,
+            })
+        })
+        .collect()
+}
+
+/// Literals of `text` within `tolerance` of a palette color but not equal to it, each with the
+/// closest such color
+///
+/// Names are left out, they are too often plain words. Colors only match palette colors of the
+/// same opacity. Hex colors of Android sources are read alpha first.
+pub fn find_drift(
+    file: &Path,
+    text: &str,
+    palette: &[PaletteEntry],
+    tolerance: f64,
+) -> Vec<(Range<usize>, Drift)> {
+    find_colors(file, text)
+        .into_iter()
+        .filter(|found| found.format != ColorFormat::Named && !in_selector(text, found))
+        .filter_map(|found| {
+            let (entry, distance) = closest(&found.color, palette)?;
+            if distance > tolerance || same_hex(&found.color, &entry.color) {
+                return None;
+            }
+            let (line, column) = position(text, found.span.start);
+            let occurrence = Occurrence {
+                file: file.to_path_buf(),
+                line,
+                column,
+                text: text[found.span.clone()].to_string(),
+                format: found.format.to_string(),
+            };
+            let drift = Drift {
+                occurrence,
+                entry: entry.clone(),
+                distance,
+            };
+            Some((found.span, drift))
+        })
+        .collect()
+}
+
+/// Drifting literals of every file of `paths`, as listed by [`source_files`]
+pub fn find_drift_in_paths(
+    paths: &[PathBuf],
+    palette: &[PaletteEntry],
+    tolerance: f64,
+) -> io::Result<Vec<Drift>> {
+    let mut drifts = vec![];
+    for file in source_files(paths)? {
+        if let Some(text) = read_source(&file)? {
+            drifts.extend(
+                find_drift(&file, &text, palette, tolerance)
+                    .into_iter()
+                    .map(|(_, drift)| drift),
+            );
+        }
+    }
+    Ok(drifts)
+}
+
+/// Replaces the drifting literals of `text`, read as being in `file`, with their palette color
+/// or token
+///
+/// Returns the new text along with how many literals were replaced.
+pub fn snap_text(
+    file: &Path,
+    text: &str,
+    palette: &[PaletteEntry],
+    tolerance: f64,
+    target: SnapTarget,
+) -> (String, usize) {
+    let drifts = find_drift(file, text, palette, tolerance);
+    let alpha_first = is_alpha_first(file);
+    let mut snapped = String::with_capacity(text.len());
+    let mut end = 0;
+    for (span, drift) in &drifts {
+        snapped += &text[end..span.start];
+        snapped += &drift.entry.replacement(target, alpha_first);
+        end = span.end;
+    }
+    snapped += &text[end..];
+    (snapped, drifts.len())
+}
+
+/// Snaps the files of `paths`, as listed by [`source_files`], returning those that changed
+pub fn snap_paths(
+    paths: &[PathBuf],
+    palette: &[PaletteEntry],
+    tolerance: f64,
+    target: SnapTarget,
+) -> io::Result<Vec<FileRewrite>> {
+    let mut rewrites = vec![];
+    for file in source_files(paths)? {
+        let Some(original) = read_source(&file)? else {
+            continue;
+        };
+        let (rewritten, count) = snap_text(&file, &original, palette, tolerance, target);
+        if count > 0 {
+            rewrites.push(FileRewrite {
+                file,
+                original,
+                rewritten,
+                count,
+            });
+        }
+    }
+    Ok(rewrites)
+}
+
+/// Palette color of the same opacity closest to `color`, with its deltaE OK
+fn closest<'a>(color: &Color, palette: &'a [PaletteEntry]) -> Option<(&'a PaletteEntry, f64)> {
+    let target = OkLab::from(color.clone());
+    palette
+        .iter()
+        .filter(|entry| alpha_byte(&entry.color) == alpha_byte(color))
+        .map(|entry| (entry, OkLab::from(entry.color.clone()).delta_e(&target)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+}
+
+/// Whether both colors are written the same in hex, the precision colors are usually picked at
+fn same_hex(a: &Color, b: &Color) -> bool {
+    format!("{:X}", RGB::from(a.clone())) == format!("{:X}", RGB::from(b.clone()))
+}
+
+fn alpha_byte(color: &Color) -> u8 {
+    (color.alpha() * 255.0).round() as u8
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+pub mod tests {
+    use std::{fs, path::Path};
+
+    use colorizer::color::{Color, RGB};
+
+    use crate::{
+        scan::position,
+        snap::{
+            DEFAULT_TOLERANCE, SnapTarget, find_drift, find_drift_in_paths, parse_palette,
+            snap_paths, snap_text,
+        },
+    };
+
+    const PALETTE: &str = ":root {\n  --brand-blue: #3b82f6;\n  --danger: rgb(239 68 68);\n}\n$muted: #6b7280;\n\"white\": \"#ffffff\",\n#000000\n";
+
+    #[test]
+    fn test_parse_palette() {
+        let palette = parse_palette(Path::new("palette.css"), PALETTE);
+        let tokens: Vec<(Option<&str>, &str)> = palette
+            .iter()
+            .map(|entry| (entry.token.as_deref(), entry.text.as_str()))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (Some("--brand-blue"), "#3b82f6"),
+                (Some("--danger"), "rgb(239 68 68)"),
+                (Some("$muted"), "#6b7280"),
+                (Some("white"), "#ffffff"),
+                (None, "#000000"),
+            ]
+        );
+        assert_eq!(palette[1].color, Color::from(RGB::new(239, 68, 68)));
+    }
+
+    #[test]
+    fn test_find_drift() {
+        let palette = parse_palette(Path::new("palette.css"), PALETTE);
+        let text = "a { color: #3b82f5; background: #3B82F6; }\nb { color: #ef4445; border-color: #ff00ff; }";
+        let drifts = find_drift(Path::new("a.css"), text, &palette, DEFAULT_TOLERANCE);
+        let found: Vec<(usize, usize, &str, &str)> = drifts
+            .iter()
+            .map(|(_, drift)| {
+                let found = &drift.occurrence;
+                (
+                    found.line,
+                    found.column,
+                    found.text.as_str(),
+                    drift.entry.text.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (1, 12, "#3b82f5", "#3b82f6"),
+                (2, 12, "#ef4445", "rgb(239 68 68)")
+            ]
+        );
+        assert!(drifts[0].1.distance > 0.0 && drifts[0].1.distance < 0.002);
+
+        // Translucent variants are intentional, not drift
+        let translucent = find_drift(Path::new("a.css"), "#3b82f580", &palette, 1.0);
+        assert!(translucent.is_empty());
+    }
+
+    #[test]
+    fn test_snap_text() {
+        let palette = parse_palette(Path::new("palette.css"), PALETTE);
+        let text = "color: #3b82f5; fill: #6b7281; stroke: #fefefe;";
+        assert_eq!(
+            snap_text(
+                Path::new("a.css"),
+                text,
+                &palette,
+                DEFAULT_TOLERANCE,
+                SnapTarget::Value
+            ),
+            (
+                String::from("color: #3b82f6; fill: #6b7280; stroke: #ffffff;"),
+                3
+            )
+        );
+        assert_eq!(
+            snap_text(
+                Path::new("a.css"),
+                text,
+                &palette,
+                DEFAULT_TOLERANCE,
+                SnapTarget::Token
+            )
+            .0,
+            "color: var(--brand-blue); fill: $muted; stroke: white;"
+        );
+        assert_eq!(position("ab\ncd", 4), (2, 2));
+    }
+
+    #[test]
+    fn test_snap_android_alpha_first() {
+        let palette = parse_palette(
+            Path::new("colors.xml"),
+            "<color name=\"scrim\">#803b82f6</color>\n<color name=\"ink\">#ff1f2937</color>",
+        );
+        assert_eq!(palette[0].text, "#3b82f680");
+        assert_eq!(
+            palette[0].color,
+            Color::try_parse("#3b82f680".to_string()).unwrap()
+        );
+
+        let text = "val scrim = \"#803b82f5\"\nval ink = \"#ff1f2938\"";
+        assert_eq!(
+            snap_text(
+                Path::new("Theme.kt"),
+                text,
+                &palette,
+                DEFAULT_TOLERANCE,
+                SnapTarget::Value
+            ),
+            (
+                String::from("val scrim = \"#803b82f6\"\nval ink = \"#ff1f2937\""),
+                2
+            )
+        );
+        // The same palette written into a stylesheet keeps CSS order
+        assert_eq!(
+            snap_text(
+                Path::new("a.css"),
+                "color: #3b82f580;",
+                &palette,
+                DEFAULT_TOLERANCE,
+                SnapTarget::Value
+            )
+            .0,
+            "color: #3b82f680;"
+        );
+    }
+
+    #[test]
+    fn test_snap_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let palette_file = root.path().join("palette.css");
+        let style = root.path().join("style.scss");
+        fs::write(&palette_file, PALETTE).unwrap();
+        fs::write(&style, ".a { color: #3b82f5; }\n").unwrap();
+        let palette = parse_palette(Path::new("palette.css"), PALETTE);
+        let paths = [root.path().to_path_buf()];
+
+        let drifts = find_drift_in_paths(&paths, &palette, DEFAULT_TOLERANCE).unwrap();
+        assert_eq!(drifts.len(), 1);
+        assert_eq!(drifts[0].occurrence.file, style);
+
+        let rewrites = snap_paths(&paths, &palette, DEFAULT_TOLERANCE, SnapTarget::Token).unwrap();
+        assert_eq!(rewrites.len(), 1);
+        rewrites[0].write().unwrap();
+        assert_eq!(
+            fs::read_to_string(&style).unwrap(),
+            ".a { color: var(--brand-blue); }\n"
+        );
+    }
+}